@@ -1,4 +1,7 @@
 use sea_orm::entity::prelude::*;
+use std::str::FromStr;
+
+use super::{DataCenter, Expac, World};
 
 #[derive(Clone, Debug, DeriveEntityModel)]
 #[sea_orm(table_name = "dashboards")]
@@ -7,6 +10,46 @@ pub struct Model {
     pub id: i64,
     pub message_id: i64,
     pub channel_id: i64,
+    /// Hash of the content last posted, see `Post::hash`.
+    pub content_hash: Option<i64>,
+    /// Whether to post a new message if this one is deleted.
+    pub sticky: bool,
+    /// Comma-separated worlds to show, or all worlds if unset.
+    pub worlds: Option<String>,
+    /// Comma-separated expansions to show, or all expansions if unset.
+    pub expacs: Option<String>,
+    /// Show worlds as rows and expansions as columns.
+    pub transposed: bool,
+    /// Order worlds by their soonest force time rather than by name.
+    pub sort_by_force: bool,
+    /// Leave out explanatory text and use short labels.
+    pub compact: bool,
+    /// Only show worlds of this data center.
+    pub data_center: Option<DataCenter>,
+    /// Show counts of trains per data center instead of individual trains.
+    pub overview: bool,
+}
+
+impl Model {
+    pub fn shows_world(&self, world: World) -> bool {
+        self.data_center
+            .is_none_or(|dc| world.data_center() == Some(dc))
+            && filter_includes(&self.worlds, world)
+    }
+
+    pub fn shows_expac(&self, expac: Expac) -> bool {
+        filter_includes(&self.expacs, expac)
+    }
+}
+
+pub(crate) fn filter_includes<T: FromStr + PartialEq + Copy>(
+    filter: &Option<String>,
+    value: T,
+) -> bool {
+    match filter {
+        Some(filter) => filter.split(',').any(|v| v.parse().ok() == Some(value)),
+        None => true,
+    }
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -0,0 +1,86 @@
+use chrono::{DateTime, Duration, Utc};
+use sea_orm::entity::prelude::*;
+use sea_orm::ConnectionTrait;
+use std::collections::HashMap;
+
+use super::locale::{Locale, Text};
+use super::train::{self, Status};
+
+/// Shown in place of the status emoji of a train whose status has expired.
+pub const STALE_EMOJI: &str = "⌛";
+
+/// A guild's override of how long a train keeps one `Status` without an
+/// update before it is shown as stale.
+#[derive(Clone, Debug, DeriveEntityModel)]
+#[sea_orm(table_name = "expiry_rules")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i64,
+    pub guild_id: i64,
+    pub status: Status,
+    /// Hours until the status expires; 0 if it never does.
+    pub hours: i64,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+/// How long a status lasts unless a guild says otherwise: known marks go
+/// stale once they are likely to have been killed, and runs rarely take
+/// longer than a couple of hours.
+fn default_hours(status: Status) -> i64 {
+    match status {
+        Status::Scouted | Status::Partial | Status::Abandoned => 6,
+        Status::Running => 2,
+        _ => 0,
+    }
+}
+
+/// What is shown instead of `status` once it has expired: a scout that may
+/// no longer be accurate, and otherwise that nothing is known.
+pub fn stale_label(status: Status, locale: Locale) -> &'static str {
+    match status {
+        Status::Scouted | Status::Partial | Status::Abandoned => locale.text(Text::StaleScout),
+        _ => locale.status(Status::Unknown),
+    }
+}
+
+/// All of a guild's expiry rules, falling back to the defaults.
+#[derive(Clone, Debug, Default)]
+pub struct Expiry {
+    hours: HashMap<Status, i64>,
+}
+
+impl Expiry {
+    pub async fn load(db: &impl ConnectionTrait, guild_id: i64) -> Result<Self, DbErr> {
+        let hours = Entity::find()
+            .filter(Column::GuildId.eq(guild_id))
+            .all(db)
+            .await?
+            .into_iter()
+            .map(|row| (row.status, row.hours))
+            .collect();
+        Ok(Expiry { hours })
+    }
+
+    /// How long `status` lasts without an update, if it expires at all.
+    pub fn limit(&self, status: Status) -> Option<Duration> {
+        let hours = self
+            .hours
+            .get(&status)
+            .copied()
+            .unwrap_or_else(|| default_hours(status));
+        (hours > 0).then(|| Duration::hours(hours))
+    }
+
+    /// When the status of `train` expires, if it does.
+    pub fn expires_at(&self, train: &train::Model) -> Option<DateTime<Utc>> {
+        Some(train.updated_at? + self.limit(train.status)?)
+    }
+
+    pub fn is_stale(&self, train: &train::Model, now: DateTime<Utc>) -> bool {
+        self.expires_at(train).is_some_and(|t| t <= now)
+    }
+}
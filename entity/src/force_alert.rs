@@ -0,0 +1,141 @@
+use poise::serenity_prelude as serenity;
+use poise::SlashArgument;
+use sea_orm::entity::prelude::*;
+use sea_orm::sea_query::{self, SeaRc};
+use sea_orm::Iterable;
+use strum_macros::{AsRefStr, Display, EnumString};
+
+use super::locale::{choice_localizations, Locale, Text};
+
+/// An alert posted to a channel when trains are about to be, or have just
+/// been, forced.
+#[derive(Clone, Debug, DeriveEntityModel)]
+#[sea_orm(table_name = "force_alerts")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i64,
+    pub channel_id: i64,
+    pub kind: AlertKind,
+    /// Role pinged with the alert; members opt in by taking the role.
+    pub role_id: Option<i64>,
+    /// How many minutes before the force time a `Soon` alert is posted.
+    pub minutes: i64,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Display, Debug, EnumIter, EnumString, AsRefStr)]
+#[strum(serialize_all = "snake_case")]
+pub enum AlertKind {
+    /// A set time before the force time.
+    Soon,
+    /// At the force time.
+    Forced,
+}
+
+impl AlertKind {
+    /// The name of the kind shown to users.
+    pub fn name(&self, locale: Locale) -> &'static str {
+        locale.text(match self {
+            AlertKind::Soon => Text::AlertSoon,
+            AlertKind::Forced => Text::AlertForced,
+        })
+    }
+}
+
+#[poise::async_trait]
+impl SlashArgument for AlertKind {
+    async fn extract(
+        _ctx: &serenity::Context,
+        _interaction: poise::ApplicationCommandOrAutocompleteInteraction<'_>,
+        value: &poise::serenity_prelude::json::Value,
+    ) -> Result<Self, poise::SlashArgError> {
+        let choice = value
+            .as_u64()
+            .ok_or(poise::SlashArgError::CommandStructureMismatch(
+                "expected u64",
+            ))?;
+        Self::iter()
+            // TODO: technically this can overflow on 32-bit systems
+            .nth(choice as usize)
+            .ok_or(poise::SlashArgError::CommandStructureMismatch(
+                "argument out of range",
+            ))
+    }
+
+    fn create(builder: &mut serenity::CreateApplicationCommandOption) {
+        builder.kind(poise::serenity_prelude::CommandOptionType::Integer);
+    }
+
+    fn choices() -> Vec<poise::CommandParameterChoice> {
+        Self::iter()
+            .map(|kind| poise::CommandParameterChoice {
+                name: kind.to_string(),
+                localizations: choice_localizations(|locale| kind.name(locale).to_owned()),
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Iden)]
+pub struct AlertKindEnum;
+
+impl ActiveEnum for AlertKind {
+    type Value = String;
+
+    fn name() -> sea_orm::DynIden {
+        SeaRc::new(AlertKindEnum)
+    }
+
+    fn to_value(&self) -> Self::Value {
+        self.to_string()
+    }
+
+    fn try_from_value(v: &Self::Value) -> Result<Self, DbErr> {
+        v.parse::<Self>().map_err(|e| DbErr::Type(e.to_string()))
+    }
+
+    fn db_type() -> ColumnDef {
+        ColumnType::String(None).def()
+    }
+}
+
+impl From<AlertKind> for sea_orm::Value {
+    fn from(kind: AlertKind) -> Self {
+        kind.into_value().into()
+    }
+}
+
+impl sea_orm::TryGetable for AlertKind {
+    fn try_get(res: &QueryResult, pre: &str, col: &str) -> Result<Self, sea_orm::TryGetError> {
+        let value = <<Self as ActiveEnum>::Value as sea_orm::TryGetable>::try_get(res, pre, col)?;
+        <Self as sea_orm::ActiveEnum>::try_from_value(&value).map_err(sea_orm::TryGetError::DbErr)
+    }
+}
+
+impl sea_query::ValueType for AlertKind {
+    fn try_from(v: Value) -> Result<Self, sea_query::ValueTypeErr> {
+        let value =
+            <<Self as sea_orm::ActiveEnum>::Value as sea_orm::sea_query::ValueType>::try_from(v)?;
+        <Self as sea_orm::ActiveEnum>::try_from_value(&value)
+            .map_err(|_| sea_orm::sea_query::ValueTypeErr)
+    }
+
+    fn type_name() -> String {
+        <<Self as sea_orm::ActiveEnum>::Value as sea_orm::sea_query::ValueType>::type_name()
+    }
+
+    fn array_type() -> sea_query::ArrayType {
+        unimplemented!("Array of enum is not supported.")
+    }
+
+    fn column_type() -> sea_query::ColumnType {
+        <Self as sea_orm::ActiveEnum>::db_type()
+            .get_column_type()
+            .to_owned()
+            .into()
+    }
+}
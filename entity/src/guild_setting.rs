@@ -0,0 +1,19 @@
+use sea_orm::entity::prelude::*;
+
+use super::locale::Locale;
+
+/// Settings that apply to everything the bot posts in a guild.
+#[derive(Clone, Debug, DeriveEntityModel)]
+#[sea_orm(table_name = "guild_settings")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i64,
+    #[sea_orm(unique)]
+    pub guild_id: i64,
+    pub locale: Locale,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -1,7 +1,23 @@
 pub mod dashboard;
+pub mod expiry_rule;
+pub mod force_alert;
+pub mod guild_setting;
+pub mod locale;
+pub mod maintenance_window;
+pub mod mark;
+pub mod mark_kill;
 pub mod monitor;
+pub mod notice_channel;
+pub mod recurring_train;
+pub mod s_rank;
+pub mod s_rank_dashboard;
+pub mod scheduled_train;
+pub mod template;
+pub mod theme;
 pub mod train;
+pub mod user_setting;
 
+use locale::{choice_localizations, Locale};
 use poise::serenity_prelude as serenity;
 use poise::SlashArgument;
 use sea_orm::entity::prelude::*;
@@ -9,11 +25,82 @@ use sea_orm::sea_query::{self, SeaRc};
 use sea_orm::{EnumIter, Iterable};
 use strum_macros::{AsRefStr, Display, EnumString, FromRepr};
 
+/// Input that does not name exactly one value.
+#[derive(Debug)]
+pub struct LookupError {
+    message: String,
+    /// Whether the input named several values rather than none.
+    pub ambiguous: bool,
+}
+
+impl std::fmt::Display for LookupError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for LookupError {}
+
+/// Every value with a name starting with `input`, ignoring case, or only the
+/// value with exactly that name if there is one.
+fn matching<T: Copy>(
+    input: &str,
+    values: impl Iterator<Item = T>,
+    names: impl Fn(T) -> Vec<String>,
+) -> Vec<T> {
+    let input = input.trim().to_lowercase();
+    let mut matches = Vec::new();
+    for value in values {
+        let names: Vec<_> = names(value).iter().map(|n| n.to_lowercase()).collect();
+        if names.contains(&input) {
+            return vec![value];
+        }
+        if names.iter().any(|n| n.starts_with(&input)) {
+            matches.push(value);
+        }
+    }
+    matches
+}
+
+fn lookup<T: std::fmt::Display>(
+    kind: &str,
+    input: &str,
+    mut matches: Vec<T>,
+) -> Result<T, LookupError> {
+    match matches.len() {
+        0 => Err(LookupError {
+            message: format!("Unknown {} \"{}\"", kind, input.trim()),
+            ambiguous: false,
+        }),
+        1 => Ok(matches.remove(0)),
+        _ => {
+            let names: Vec<_> = matches.iter().map(|m| m.to_string()).collect();
+            Err(LookupError {
+                message: format!("\"{}\" could be {}", input.trim(), names.join(" or ")),
+                ambiguous: true,
+            })
+        }
+    }
+}
+
 #[derive(Copy, Clone, Debug, EnumIter, Iden)]
 pub enum Table {
     Trains,
     Monitors,
     Dashboards,
+    Themes,
+    Templates,
+    GuildSettings,
+    UserSettings,
+    MaintenanceWindows,
+    NoticeChannels,
+    ScheduledTrains,
+    RecurringTrains,
+    ForceAlerts,
+    ExpiryRules,
+    MarkKills,
+    SRanks,
+    SRankDashboards,
 }
 
 #[derive(
@@ -41,6 +128,140 @@ pub enum World {
 
 #[poise::async_trait]
 impl SlashArgument for World {
+    async fn extract(
+        _ctx: &serenity::Context,
+        _interaction: poise::ApplicationCommandOrAutocompleteInteraction<'_>,
+        value: &poise::serenity_prelude::json::Value,
+    ) -> Result<Self, poise::SlashArgError> {
+        let input = value
+            .as_str()
+            .ok_or(poise::SlashArgError::CommandStructureMismatch(
+                "expected string",
+            ))?;
+        Self::lookup(input).map_err(|e| poise::SlashArgError::Parse {
+            error: Box::new(e),
+            input: input.to_owned(),
+        })
+    }
+
+    fn create(builder: &mut serenity::CreateApplicationCommandOption) {
+        builder.kind(poise::serenity_prelude::CommandOptionType::String);
+    }
+
+    fn choices() -> Vec<poise::CommandParameterChoice> {
+        // Picked through autocomplete instead, so that aliases and prefixes work.
+        Vec::new()
+    }
+}
+
+#[derive(Debug, Iden)]
+pub struct WorldEnum;
+
+impl ActiveEnum for World {
+    type Value = String;
+
+    fn name() -> sea_orm::DynIden {
+        SeaRc::new(WorldEnum)
+    }
+
+    fn to_value(&self) -> Self::Value {
+        self.to_string()
+    }
+
+    fn try_from_value(v: &Self::Value) -> Result<Self, DbErr> {
+        v.parse::<Self>().map_err(|e| DbErr::Type(e.to_string()))
+    }
+
+    fn db_type() -> ColumnDef {
+        ColumnType::String(None).def()
+    }
+}
+
+impl From<World> for sea_orm::Value {
+    fn from(world: World) -> Self {
+        world.into_value().into()
+    }
+}
+
+impl sea_orm::TryGetable for World {
+    fn try_get(res: &QueryResult, pre: &str, col: &str) -> Result<Self, sea_orm::TryGetError> {
+        let value = <<Self as ActiveEnum>::Value as sea_orm::TryGetable>::try_get(res, pre, col)?;
+        <Self as sea_orm::ActiveEnum>::try_from_value(&value).map_err(sea_orm::TryGetError::DbErr)
+    }
+}
+
+impl sea_query::ValueType for World {
+    fn try_from(v: Value) -> Result<Self, sea_query::ValueTypeErr> {
+        let value =
+            <<Self as sea_orm::ActiveEnum>::Value as sea_orm::sea_query::ValueType>::try_from(v)?;
+        <Self as sea_orm::ActiveEnum>::try_from_value(&value)
+            .map_err(|_| sea_orm::sea_query::ValueTypeErr)
+    }
+
+    fn type_name() -> String {
+        <<Self as sea_orm::ActiveEnum>::Value as sea_orm::sea_query::ValueType>::type_name()
+    }
+
+    fn array_type() -> sea_query::ArrayType {
+        unimplemented!("Array of enum is not supported.")
+    }
+
+    fn column_type() -> sea_query::ColumnType {
+        <Self as sea_orm::ActiveEnum>::db_type()
+            .get_column_type()
+            .to_owned()
+            .into()
+    }
+}
+
+impl World {
+    /// Worlds whose name starts with `input`, ignoring case.
+    pub fn matching(input: &str) -> Vec<Self> {
+        matching(input, Self::iter(), |world| vec![world.to_string()])
+    }
+
+    /// The world named by `input`, which may be any unambiguous prefix.
+    pub fn lookup(input: &str) -> Result<Self, LookupError> {
+        lookup("world", input, Self::matching(input))
+    }
+
+    /// The data center the world belongs to, if it is a real world.
+    pub fn data_center(&self) -> Option<DataCenter> {
+        use World::*;
+        match self {
+            Halicarnassus | Maduin | Marilith | Seraph => Some(DataCenter::Dynamis),
+            Testing => None,
+        }
+    }
+}
+
+#[derive(
+    Copy,
+    Clone,
+    PartialOrd,
+    Ord,
+    PartialEq,
+    Eq,
+    Hash,
+    Display,
+    Debug,
+    EnumIter,
+    EnumString,
+    AsRefStr,
+)]
+#[strum(ascii_case_insensitive)]
+pub enum DataCenter {
+    Dynamis,
+}
+
+impl DataCenter {
+    pub fn worlds(&self) -> impl Iterator<Item = World> + '_ {
+        World::iter().filter(move |world| world.data_center() == Some(*self))
+    }
+}
+
+#[poise::async_trait]
+impl SlashArgument for DataCenter {
     async fn extract(
         _ctx: &serenity::Context,
         _interaction: poise::ApplicationCommandOrAutocompleteInteraction<'_>,
@@ -65,25 +286,23 @@ impl SlashArgument for World {
 
     fn choices() -> Vec<poise::CommandParameterChoice> {
         Self::iter()
-            .map(|world| poise::CommandParameterChoice {
-                name: world.to_string(),
-                localizations: std::collections::HashMap::from([(
-                    "en-US".to_owned(),
-                    world.to_string(),
-                )]),
+            .map(|dc| poise::CommandParameterChoice {
+                name: dc.to_string(),
+                // Proper nouns, the same in every language.
+                localizations: choice_localizations(|_| dc.to_string()),
             })
             .collect()
     }
 }
 
 #[derive(Debug, Iden)]
-pub struct WorldEnum;
+pub struct DataCenterEnum;
 
-impl ActiveEnum for World {
+impl ActiveEnum for DataCenter {
     type Value = String;
 
     fn name() -> sea_orm::DynIden {
-        SeaRc::new(WorldEnum)
+        SeaRc::new(DataCenterEnum)
     }
 
     fn to_value(&self) -> Self::Value {
@@ -99,20 +318,26 @@ impl ActiveEnum for World {
     }
 }
 
-impl From<World> for sea_orm::Value {
-    fn from(world: World) -> Self {
-        world.into_value().into()
+impl From<DataCenter> for sea_orm::Value {
+    fn from(dc: DataCenter) -> Self {
+        dc.into_value().into()
     }
 }
 
-impl sea_orm::TryGetable for World {
+impl sea_orm::TryGetable for DataCenter {
     fn try_get(res: &QueryResult, pre: &str, col: &str) -> Result<Self, sea_orm::TryGetError> {
         let value = <<Self as ActiveEnum>::Value as sea_orm::TryGetable>::try_get(res, pre, col)?;
         <Self as sea_orm::ActiveEnum>::try_from_value(&value).map_err(sea_orm::TryGetError::DbErr)
     }
 }
 
-impl sea_query::ValueType for World {
+impl sea_query::Nullable for DataCenter {
+    fn null() -> Value {
+        Value::String(None)
+    }
+}
+
+impl sea_query::ValueType for DataCenter {
     fn try_from(v: Value) -> Result<Self, sea_query::ValueTypeErr> {
         let value =
             <<Self as sea_orm::ActiveEnum>::Value as sea_orm::sea_query::ValueType>::try_from(v)?;
@@ -135,6 +360,7 @@ impl sea_query::ValueType for World {
             .into()
     }
 }
+
 #[derive(
     Copy,
     Clone,
@@ -165,6 +391,38 @@ pub enum Expac {
     ARR = 2,
 }
 
+impl Expac {
+    /// Expansions with a name or abbreviation starting with `input`, ignoring
+    /// case. Names in every supported language are accepted.
+    pub fn matching(input: &str) -> Vec<Self> {
+        matching(input, Self::iter(), |expac| {
+            let mut names = vec![expac.to_string(), expac.short_name().to_owned()];
+            if expac == Expac::StB {
+                names.push("StB".to_owned());
+            }
+            names.extend(Locale::iter().map(|locale| locale.expac(expac).to_owned()));
+            names
+        })
+    }
+
+    /// The expansion named by `input`, which may be any unambiguous prefix.
+    pub fn lookup(input: &str) -> Result<Self, LookupError> {
+        lookup("expansion", input, Self::matching(input))
+    }
+
+    /// The abbreviation commonly used for the expansion.
+    pub fn short_name(&self) -> &'static str {
+        use Expac::*;
+        match self {
+            EW => "EW",
+            ShB => "ShB",
+            StB => "SB",
+            HW => "HW",
+            ARR => "ARR",
+        }
+    }
+}
+
 #[derive(Debug, Iden)]
 pub struct ExpacEnum;
 
@@ -175,33 +433,24 @@ impl SlashArgument for Expac {
         _interaction: poise::ApplicationCommandOrAutocompleteInteraction<'_>,
         value: &poise::serenity_prelude::json::Value,
     ) -> Result<Self, poise::SlashArgError> {
-        let choice = value
-            .as_u64()
+        let input = value
+            .as_str()
             .ok_or(poise::SlashArgError::CommandStructureMismatch(
-                "expected u64",
+                "expected string",
             ))?;
-        Self::iter()
-            // TODO: technically this can overflow on 32-bit systems
-            .nth(choice as usize)
-            .ok_or(poise::SlashArgError::CommandStructureMismatch(
-                "argument out of range",
-            ))
+        Self::lookup(input).map_err(|e| poise::SlashArgError::Parse {
+            error: Box::new(e),
+            input: input.to_owned(),
+        })
     }
 
     fn create(builder: &mut serenity::CreateApplicationCommandOption) {
-        builder.kind(poise::serenity_prelude::CommandOptionType::Integer);
+        builder.kind(poise::serenity_prelude::CommandOptionType::String);
     }
 
     fn choices() -> Vec<poise::CommandParameterChoice> {
-        Self::iter()
-            .map(|expac| poise::CommandParameterChoice {
-                name: expac.to_string(),
-                localizations: std::collections::HashMap::from([(
-                    "en-US".to_owned(),
-                    expac.to_string(),
-                )]),
-            })
-            .collect()
+        // Picked through autocomplete instead, so that aliases and prefixes work.
+        Vec::new()
     }
 }
 
@@ -0,0 +1,268 @@
+use poise::serenity_prelude as serenity;
+use poise::SlashArgument;
+use sea_orm::entity::prelude::*;
+use sea_orm::sea_query::{self, SeaRc};
+use sea_orm::{ConnectionTrait, Iterable};
+use std::collections::HashMap;
+use strum_macros::Display;
+
+use super::template::TemplateKey;
+use super::train::Status;
+use super::{guild_setting, Expac};
+
+mod de;
+mod en;
+mod fr;
+mod ja;
+
+/// A language we have translations for.
+#[derive(Copy, Clone, Default, PartialEq, Eq, Hash, Display, Debug, EnumIter)]
+pub enum Locale {
+    #[default]
+    English,
+    #[strum(to_string = "日本語")]
+    Japanese,
+    #[strum(to_string = "Français")]
+    French,
+    #[strum(to_string = "Deutsch")]
+    German,
+}
+
+/// Text shown in posts that is not a template.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Text {
+    DashboardTitle,
+    OverviewTitle,
+    World,
+    Expansion,
+    Status,
+    Ready,
+    NextForced,
+    ScoutedMap,
+    /// The `scouted` template variable, before it is linked to the map.
+    Scouted,
+    /// Notice posted when scheduled maintenance begins.
+    MaintenanceStarted,
+    /// Notice posted when scheduled maintenance ends.
+    MaintenanceEnded,
+    /// Heading of the planned trains on monitors and dashboards.
+    Schedule,
+    /// Label of the conductor in the description of a scheduled event.
+    Conductor,
+    /// Shown instead of `Scouted` once a scout has expired.
+    StaleScout,
+    /// Shown on monitors of trains whose status has expired.
+    NoUpdateSince,
+    /// Label for how many marks a partial run left alive.
+    MarksLeft,
+    SRankDashboardTitle,
+    /// Text above an S-rank dashboard, explaining its symbols.
+    SRankNote,
+    /// Reply to a reported S-rank kill.
+    SRankKilled,
+    Zone,
+    /// Name of the `soon` kind of force alert.
+    AlertSoon,
+    /// Name of the `forced` kind of force alert.
+    AlertForced,
+    AlertSetSoon,
+    AlertSetForced,
+    /// Added to `AlertSetSoon` and `AlertSetForced` when a role is pinged.
+    AlertPing,
+    AlertRemoved,
+    AlertListTitle,
+    AlertListEmpty,
+    AlertListSoon,
+    AlertListForced,
+    /// Added to `AlertListSoon` and `AlertListForced` when a role is pinged.
+    AlertListPing,
+    AlertSubscribed,
+    AlertUnsubscribed,
+    /// Reply to a command once every monitor post has been updated.
+    MonitorsUpdated,
+    /// Reply to a command when some monitor posts could not be updated.
+    MonitorsNotUpdated,
+    /// Shown instead of an empty table.
+    GridEmpty,
+    /// Shown when a table is cut short to fit in a post.
+    GridOverflow,
+    ThemeTitle,
+    /// Shown for statuses without a colour of their own.
+    ThemeDefaultColour,
+    ThemeUpdated,
+    ThemeStatusReset,
+    ThemeReset,
+}
+
+/// Strings making up one language.
+struct Catalog {
+    text: fn(Text) -> &'static str,
+    status: fn(Status) -> &'static str,
+    expac: fn(Expac) -> &'static str,
+    template: fn(TemplateKey) -> &'static str,
+    template_name: fn(TemplateKey) -> &'static str,
+}
+
+impl Locale {
+    /// Discord's code for the locale.
+    pub fn code(&self) -> &'static str {
+        use Locale::*;
+        match self {
+            English => "en-US",
+            Japanese => "ja",
+            French => "fr",
+            German => "de",
+        }
+    }
+
+    /// The locale for one of Discord's locale codes, if we support it.
+    /// Regional variants fall back to the base language.
+    pub fn from_code(code: &str) -> Option<Self> {
+        let language = code.split('-').next().unwrap_or(code);
+        Self::iter().find(|l| l.code().split('-').next() == Some(language))
+    }
+
+    /// The language a guild has picked for its posts.
+    pub async fn for_guild(db: &impl ConnectionTrait, guild_id: i64) -> Result<Self, DbErr> {
+        Ok(guild_setting::Entity::find()
+            .filter(guild_setting::Column::GuildId.eq(guild_id))
+            .one(db)
+            .await?
+            .map(|settings| settings.locale)
+            .unwrap_or_default())
+    }
+
+    fn catalog(&self) -> &'static Catalog {
+        use Locale::*;
+        match self {
+            English => &en::CATALOG,
+            Japanese => &ja::CATALOG,
+            French => &fr::CATALOG,
+            German => &de::CATALOG,
+        }
+    }
+
+    pub fn text(&self, text: Text) -> &'static str {
+        (self.catalog().text)(text)
+    }
+
+    pub fn status(&self, status: Status) -> &'static str {
+        (self.catalog().status)(status)
+    }
+
+    pub fn expac(&self, expac: Expac) -> &'static str {
+        (self.catalog().expac)(expac)
+    }
+
+    /// The wording of a template when a guild has not set its own.
+    pub fn template(&self, key: TemplateKey) -> &'static str {
+        (self.catalog().template)(key)
+    }
+
+    /// What a template is called in the list of templates to pick from.
+    pub fn template_name(&self, key: TemplateKey) -> &'static str {
+        (self.catalog().template_name)(key)
+    }
+}
+
+/// Localizations of a choice name in every supported locale.
+pub fn choice_localizations(name: impl Fn(Locale) -> String) -> HashMap<String, String> {
+    Locale::iter()
+        .map(|locale| (locale.code().to_owned(), name(locale)))
+        .collect()
+}
+
+#[poise::async_trait]
+impl SlashArgument for Locale {
+    async fn extract(
+        _ctx: &serenity::Context,
+        _interaction: poise::ApplicationCommandOrAutocompleteInteraction<'_>,
+        value: &poise::serenity_prelude::json::Value,
+    ) -> Result<Self, poise::SlashArgError> {
+        let choice = value
+            .as_u64()
+            .ok_or(poise::SlashArgError::CommandStructureMismatch(
+                "expected u64",
+            ))?;
+        Self::iter()
+            // TODO: technically this can overflow on 32-bit systems
+            .nth(choice as usize)
+            .ok_or(poise::SlashArgError::CommandStructureMismatch(
+                "argument out of range",
+            ))
+    }
+
+    fn create(builder: &mut serenity::CreateApplicationCommandOption) {
+        builder.kind(poise::serenity_prelude::CommandOptionType::Integer);
+    }
+
+    fn choices() -> Vec<poise::CommandParameterChoice> {
+        // Every language is listed under its own name.
+        Self::iter()
+            .map(|locale| poise::CommandParameterChoice {
+                name: locale.to_string(),
+                localizations: choice_localizations(|_| locale.to_string()),
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Iden)]
+pub struct LocaleEnum;
+
+impl ActiveEnum for Locale {
+    type Value = String;
+
+    fn name() -> sea_orm::DynIden {
+        SeaRc::new(LocaleEnum)
+    }
+
+    fn to_value(&self) -> Self::Value {
+        self.code().to_owned()
+    }
+
+    fn try_from_value(v: &Self::Value) -> Result<Self, DbErr> {
+        Self::from_code(v).ok_or_else(|| DbErr::Type(format!("invalid Locale value: {}", v)))
+    }
+
+    fn db_type() -> ColumnDef {
+        ColumnType::String(None).def()
+    }
+}
+
+impl From<Locale> for sea_orm::Value {
+    fn from(locale: Locale) -> Self {
+        locale.into_value().into()
+    }
+}
+
+impl sea_orm::TryGetable for Locale {
+    fn try_get(res: &QueryResult, pre: &str, col: &str) -> Result<Self, sea_orm::TryGetError> {
+        let value = <<Self as ActiveEnum>::Value as sea_orm::TryGetable>::try_get(res, pre, col)?;
+        <Self as sea_orm::ActiveEnum>::try_from_value(&value).map_err(sea_orm::TryGetError::DbErr)
+    }
+}
+
+impl sea_query::ValueType for Locale {
+    fn try_from(v: Value) -> Result<Self, sea_query::ValueTypeErr> {
+        let value =
+            <<Self as sea_orm::ActiveEnum>::Value as sea_orm::sea_query::ValueType>::try_from(v)?;
+        <Self as sea_orm::ActiveEnum>::try_from_value(&value)
+            .map_err(|_| sea_orm::sea_query::ValueTypeErr)
+    }
+
+    fn type_name() -> String {
+        <<Self as sea_orm::ActiveEnum>::Value as sea_orm::sea_query::ValueType>::type_name()
+    }
+
+    fn array_type() -> sea_query::ArrayType {
+        unimplemented!("Array of enum is not supported.")
+    }
+
+    fn column_type() -> sea_query::ColumnType {
+        <Self as sea_orm::ActiveEnum>::db_type()
+            .get_column_type()
+            .to_owned()
+            .into()
+    }
+}
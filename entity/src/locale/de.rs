@@ -0,0 +1,132 @@
+use super::{Catalog, Text};
+use crate::template::TemplateKey;
+use crate::train::Status;
+use crate::Expac;
+
+pub(super) const CATALOG: Catalog = Catalog {
+    text,
+    status,
+    expac,
+    template,
+    template_name,
+};
+
+fn text(text: Text) -> &'static str {
+    use Text::*;
+    match text {
+        DashboardTitle => "Zug-Dashboard",
+        OverviewTitle => "Zugübersicht",
+        World => "Welt",
+        Expansion => "Erweiterung",
+        Status => "Status",
+        Ready => "Bereit",
+        NextForced => "Nächster erzwungener",
+        ScoutedMap => "Scout-Karte",
+        Scouted => "gescoutet",
+        MaintenanceStarted => {
+            "Die Serverwartung hat begonnen. Die Zugzeiten sind bis zu ihrem Ende zurückgesetzt."
+        }
+        MaintenanceEnded => {
+            "Die Serverwartung ist beendet. Die Hohen Ziele nach der Wartung erscheinen."
+        }
+        Schedule => "Fahrplan",
+        Conductor => "Zugführer",
+        StaleScout => "Veralteter Scout",
+        NoUpdateSince => "Keine Aktualisierung seit",
+        MarksLeft => "Verbleibende Marks",
+        SRankDashboardTitle => "S-Rang-Dashboard",
+        SRankNote => "🕑 Fenster öffnet · 🟢 Im Fenster, bis zum spätesten Spawn · 🔥 Nach dem spätesten Spawn; Spawnbedingungen prüfen",
+        SRankKilled => "S-Rang in {zone} auf {world} um {kill_time} besiegt; sein Fenster öffnet {opens}",
+        Zone => "Gebiet",
+        AlertSoon => "bald",
+        AlertForced => "erzwungen",
+        AlertSetSoon => "Alarme werden hier {minutes} Minuten vor dem Erzwingen von Zügen gepostet.",
+        AlertSetForced => "Alarme werden hier beim Erzwingen von Zügen gepostet.",
+        AlertPing => " Sie erwähnen {role}.",
+        AlertRemoved => "`{kind}`-Alarme werden hier nicht mehr gepostet.",
+        AlertListTitle => "Erzwingungsalarme",
+        AlertListEmpty => "Es werden keine Alarme gepostet.",
+        AlertListSoon => "`{kind}` ({minutes} Minuten vorher) in {channel}",
+        AlertListForced => "`{kind}` in {channel}",
+        AlertListPing => ", erwähnt {role}",
+        AlertSubscribed => "Du wirst jetzt von {roles} erwähnt.",
+        AlertUnsubscribed => "Du wirst nicht mehr von {roles} erwähnt.",
+        MonitorsUpdated => "{reply}.",
+        MonitorsNotUpdated => "Fehler: {reply}, aber nicht alle Monitor-Beiträge konnten aktualisiert werden.",
+        GridEmpty => "Nichts anzuzeigen.",
+        GridOverflow => "Nicht alles passt in diesen Beitrag; einige Einträge wurden ausgelassen.",
+        ThemeTitle => "Zug-Design",
+        ThemeDefaultColour => "Standardfarbe",
+        ThemeUpdated => "Design für {status} aktualisiert",
+        ThemeStatusReset => "Design für {status} zurückgesetzt",
+        ThemeReset => "Design zurückgesetzt",
+    }
+}
+
+fn status(status: Status) -> &'static str {
+    use Status::*;
+    match status {
+        Unknown => "Unbekannt",
+        Waiting => "Wartend",
+        Scouted => "Gescoutet",
+        Running => "Läuft",
+        Maintenance => "Spawn nach Wartung",
+        Sniped => "Gesnipt",
+        Partial => "Teilweise gelaufen",
+        Abandoned => "Abgebrochen",
+    }
+}
+
+fn expac(expac: Expac) -> &'static str {
+    // The German client uses the English names.
+    use Expac::*;
+    match expac {
+        EW => "Endwalker",
+        ShB => "Shadowbringers",
+        StB => "Stormblood",
+        HW => "Heavensward",
+        ARR => "A Realm Reborn",
+    }
+}
+
+fn template(key: TemplateKey) -> &'static str {
+    use TemplateKey::*;
+    match key {
+        MonitorTitle => "{world} {expac} Zug",
+        MonitorStatus => "{emoji} {status}",
+        MonitorLastRun => "Letzter Lauf beendet am: {last_run}",
+        MonitorForce => "Erzwungen {force_time}",
+        DashboardNote => "Angegebene Zeiten sind erzwungene Spawnzeiten (Obergrenze).",
+        OverviewNote => "Bereite Züge hatten ihre erzwungene Spawnzeit (Obergrenze) bei der letzten Aktualisierung überschritten.",
+        Scouted => "{world} {expac} Zug wurde {scouted}",
+        Started => "{world} {expac} Zug läuft jetzt",
+        Completed => "{world} {expac} Zug beendet am {last_run}",
+        Reminder => "⏰ {conductor} {world} {expac} Zug startet {start_time}\n{notes}",
+        ForceSoon => "{emoji} {world} {expac} Zug wird {force_time} erzwungen",
+        Forced => "{emoji} {world} {expac} Zug wurde {force_time} erzwungen",
+        Sniped => "{emoji} {world} {expac} Train wurde gesnipt; erzwungen {force_time}",
+        Partial => "{world} {expac} Train wurde mit {marks_left} verbleibenden Marks beendet",
+        Abandoned => "{world} {expac} Train wurde abgebrochen",
+    }
+}
+
+fn template_name(key: TemplateKey) -> &'static str {
+    use TemplateKey::*;
+    match key {
+        MonitorTitle => "Monitor-Titel",
+        MonitorStatus => "Monitor-Status",
+        MonitorLastRun => "Letzter Lauf im Monitor",
+        MonitorForce => "Erzwungen im Monitor",
+        DashboardNote => "Dashboard-Hinweis",
+        OverviewNote => "Übersichts-Hinweis",
+        Scouted => "Antwort auf Auskundschaften",
+        Started => "Antwort auf Start",
+        Completed => "Antwort auf Abschluss",
+        Reminder => "Erinnerung",
+        ForceSoon => "Alarm vor dem Erzwingen",
+        Forced => "Alarm beim Erzwingen",
+        Sniped => "Antwort auf Weggeschnappt",
+        Partial => "Antwort auf Teillauf",
+        Abandoned => "Antwort auf Abbruch",
+    }
+}
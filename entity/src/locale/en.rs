@@ -0,0 +1,129 @@
+use super::{Catalog, Text};
+use crate::template::TemplateKey;
+use crate::train::Status;
+use crate::Expac;
+
+pub(super) const CATALOG: Catalog = Catalog {
+    text,
+    status,
+    expac,
+    template,
+    template_name,
+};
+
+fn text(text: Text) -> &'static str {
+    use Text::*;
+    match text {
+        DashboardTitle => "Train Dashboard",
+        OverviewTitle => "Train Overview",
+        World => "World",
+        Expansion => "Expansion",
+        Status => "Status",
+        Ready => "Ready",
+        NextForced => "Next forced",
+        ScoutedMap => "Scouted Map",
+        Scouted => "scouted",
+        MaintenanceStarted => {
+            "Server maintenance has started. Train timers are reset until it ends."
+        }
+        MaintenanceEnded => "Server maintenance has ended. Post-maintenance marks are spawning.",
+        Schedule => "Schedule",
+        Conductor => "Conductor",
+        StaleScout => "Stale scout",
+        NoUpdateSince => "No update since",
+        MarksLeft => "Marks left",
+        SRankDashboardTitle => "S-Rank Dashboard",
+        SRankNote => "🕑 Window opens · 🟢 In window, until the latest spawn · 🔥 Past the latest spawn; check the spawn conditions",
+        SRankKilled => "{zone} S-rank on {world} killed at {kill_time}; its window opens {opens}",
+        Zone => "Zone",
+        AlertSoon => "soon",
+        AlertForced => "forced",
+        AlertSetSoon => "Alerts will be posted here {minutes} minutes before trains are forced.",
+        AlertSetForced => "Alerts will be posted here when trains are forced.",
+        AlertPing => " They will ping {role}.",
+        AlertRemoved => "`{kind}` alerts will no longer be posted here.",
+        AlertListTitle => "Force Alerts",
+        AlertListEmpty => "No alerts are posted.",
+        AlertListSoon => "`{kind}` ({minutes} minutes before) in {channel}",
+        AlertListForced => "`{kind}` in {channel}",
+        AlertListPing => ", pinging {role}",
+        AlertSubscribed => "You will now be pinged by {roles}.",
+        AlertUnsubscribed => "You will no longer be pinged by {roles}.",
+        MonitorsUpdated => "{reply}.",
+        MonitorsNotUpdated => "Error: {reply}, but not all monitor posts could be updated.",
+        GridEmpty => "Nothing to show.",
+        GridOverflow => "Not everything fits in this post; some entries have been left out.",
+        ThemeTitle => "Train Theme",
+        ThemeDefaultColour => "default colour",
+        ThemeUpdated => "Theme for {status} updated",
+        ThemeStatusReset => "Theme for {status} reset",
+        ThemeReset => "Theme reset",
+    }
+}
+
+fn status(status: Status) -> &'static str {
+    use Status::*;
+    match status {
+        Unknown => "Unknown",
+        Waiting => "Waiting",
+        Scouted => "Scouted",
+        Running => "Running",
+        Maintenance => "Post-maintenance spawn",
+        Sniped => "Sniped",
+        Partial => "Partially run",
+        Abandoned => "Abandoned",
+    }
+}
+
+fn expac(expac: Expac) -> &'static str {
+    use Expac::*;
+    match expac {
+        EW => "Endwalker",
+        ShB => "Shadowbringers",
+        StB => "Stormblood",
+        HW => "Heavensward",
+        ARR => "A Realm Reborn",
+    }
+}
+
+fn template(key: TemplateKey) -> &'static str {
+    use TemplateKey::*;
+    match key {
+        MonitorTitle => "{world} {expac} Train",
+        MonitorStatus => "{emoji} {status}",
+        MonitorLastRun => "Last run completed at: {last_run}",
+        MonitorForce => "Forced {force_time}",
+        DashboardNote => "Where a time is present, it indicates force (cap) time.",
+        OverviewNote => "Ready trains have passed their force (cap) time as of the last update.",
+        Scouted => "{world} {expac} Train has been {scouted}",
+        Started => "{world} {expac} Train is now running",
+        Completed => "{world} {expac} Train completed at {last_run}",
+        Reminder => "⏰ {conductor} {world} {expac} Train starts {start_time}\n{notes}",
+        ForceSoon => "{emoji} {world} {expac} Train will be forced {force_time}",
+        Forced => "{emoji} {world} {expac} Train has been forced {force_time}",
+        Sniped => "{emoji} {world} {expac} Train was sniped; forced {force_time}",
+        Partial => "{world} {expac} Train stopped with {marks_left} marks left",
+        Abandoned => "{world} {expac} Train was abandoned",
+    }
+}
+
+fn template_name(key: TemplateKey) -> &'static str {
+    use TemplateKey::*;
+    match key {
+        MonitorTitle => "Monitor title",
+        MonitorStatus => "Monitor status",
+        MonitorLastRun => "Monitor last run",
+        MonitorForce => "Monitor force time",
+        DashboardNote => "Dashboard note",
+        OverviewNote => "Overview note",
+        Scouted => "Scouted reply",
+        Started => "Started reply",
+        Completed => "Completed reply",
+        Reminder => "Reminder",
+        ForceSoon => "Force soon alert",
+        Forced => "Forced alert",
+        Sniped => "Sniped reply",
+        Partial => "Partial run reply",
+        Abandoned => "Abandoned reply",
+    }
+}
@@ -0,0 +1,128 @@
+use super::{Catalog, Text};
+use crate::template::TemplateKey;
+use crate::train::Status;
+use crate::Expac;
+
+pub(super) const CATALOG: Catalog = Catalog {
+    text,
+    status,
+    expac,
+    template,
+    template_name,
+};
+
+fn text(text: Text) -> &'static str {
+    use Text::*;
+    match text {
+        DashboardTitle => "Tableau de bord des trains",
+        OverviewTitle => "Aperçu des trains",
+        World => "Monde",
+        Expansion => "Extension",
+        Status => "Statut",
+        Ready => "Prêts",
+        NextForced => "Prochain forcé",
+        ScoutedMap => "Carte de repérage",
+        Scouted => "repéré",
+        MaintenanceStarted => "La maintenance des serveurs a commencé. Les minuteurs des trains sont réinitialisés jusqu'à la fin.",
+        MaintenanceEnded => "La maintenance des serveurs est terminée. Les cibles post-maintenance apparaissent.",
+        Schedule => "Planning",
+        Conductor => "Conducteur",
+        StaleScout => "Repérage périmé",
+        NoUpdateSince => "Pas de mise à jour depuis",
+        MarksLeft => "Marques restantes",
+        SRankDashboardTitle => "Tableau des rangs S",
+        SRankNote => "🕑 Ouverture de la fenêtre · 🟢 Dans la fenêtre, jusqu'à l'apparition la plus tardive · 🔥 Au-delà ; vérifiez les conditions d'apparition",
+        SRankKilled => "Rang S de {zone} sur {world} tué à {kill_time} ; sa fenêtre s'ouvre {opens}",
+        Zone => "Zone",
+        AlertSoon => "bientôt",
+        AlertForced => "forcé",
+        AlertSetSoon => "Des alertes seront postées ici {minutes} minutes avant que les trains soient forcés.",
+        AlertSetForced => "Des alertes seront postées ici quand les trains sont forcés.",
+        AlertPing => " Elles mentionneront {role}.",
+        AlertRemoved => "Les alertes `{kind}` ne seront plus postées ici.",
+        AlertListTitle => "Alertes de forçage",
+        AlertListEmpty => "Aucune alerte n'est postée.",
+        AlertListSoon => "`{kind}` ({minutes} minutes avant) dans {channel}",
+        AlertListForced => "`{kind}` dans {channel}",
+        AlertListPing => ", mentionne {role}",
+        AlertSubscribed => "Vous serez désormais mentionné par {roles}.",
+        AlertUnsubscribed => "Vous ne serez plus mentionné par {roles}.",
+        MonitorsUpdated => "{reply}.",
+        MonitorsNotUpdated => "Erreur : {reply}, mais certains messages de suivi n'ont pas pu être mis à jour.",
+        GridEmpty => "Rien à afficher.",
+        GridOverflow => "Tout ne tient pas dans ce message ; certaines entrées ont été omises.",
+        ThemeTitle => "Thème des trains",
+        ThemeDefaultColour => "couleur par défaut",
+        ThemeUpdated => "Thème de {status} mis à jour",
+        ThemeStatusReset => "Thème de {status} réinitialisé",
+        ThemeReset => "Thème réinitialisé",
+    }
+}
+
+fn status(status: Status) -> &'static str {
+    use Status::*;
+    match status {
+        Unknown => "Inconnu",
+        Waiting => "En attente",
+        Scouted => "Repéré",
+        Running => "En cours",
+        Maintenance => "Apparition post-maintenance",
+        Sniped => "Volé",
+        Partial => "Partiel",
+        Abandoned => "Abandonné",
+    }
+}
+
+fn expac(expac: Expac) -> &'static str {
+    // The French client uses the English names.
+    use Expac::*;
+    match expac {
+        EW => "Endwalker",
+        ShB => "Shadowbringers",
+        StB => "Stormblood",
+        HW => "Heavensward",
+        ARR => "A Realm Reborn",
+    }
+}
+
+fn template(key: TemplateKey) -> &'static str {
+    use TemplateKey::*;
+    match key {
+        MonitorTitle => "Train {expac} {world}",
+        MonitorStatus => "{emoji} {status}",
+        MonitorLastRun => "Dernier passage terminé : {last_run}",
+        MonitorForce => "Apparition forcée {force_time}",
+        DashboardNote => "Les heures indiquées sont les heures d'apparition forcée (plafond).",
+        OverviewNote => "Les trains prêts avaient dépassé leur heure d'apparition forcée (plafond) lors de la dernière mise à jour.",
+        Scouted => "Le train {expac} de {world} a été {scouted}",
+        Started => "Le train {expac} de {world} est en cours",
+        Completed => "Le train {expac} de {world} s'est terminé le {last_run}",
+        Reminder => "⏰ {conductor} Le train {expac} de {world} part {start_time}\n{notes}",
+        ForceSoon => "{emoji} Le train {expac} de {world} sera forcé {force_time}",
+        Forced => "{emoji} Le train {expac} de {world} a été forcé {force_time}",
+        Sniped => "{emoji} Le train {expac} de {world} a été volé ; forcé {force_time}",
+        Partial => "Le train {expac} de {world} s'est arrêté avec {marks_left} marques restantes",
+        Abandoned => "Le train {expac} de {world} a été abandonné",
+    }
+}
+
+fn template_name(key: TemplateKey) -> &'static str {
+    use TemplateKey::*;
+    match key {
+        MonitorTitle => "Titre du suivi",
+        MonitorStatus => "Statut du suivi",
+        MonitorLastRun => "Dernier passage du suivi",
+        MonitorForce => "Apparition forcée du suivi",
+        DashboardNote => "Note du tableau de bord",
+        OverviewNote => "Note de la vue d'ensemble",
+        Scouted => "Réponse de repérage",
+        Started => "Réponse de départ",
+        Completed => "Réponse de fin",
+        Reminder => "Rappel",
+        ForceSoon => "Alerte avant apparition forcée",
+        Forced => "Alerte d'apparition forcée",
+        Sniped => "Réponse de vol",
+        Partial => "Réponse de passage partiel",
+        Abandoned => "Réponse d'abandon",
+    }
+}
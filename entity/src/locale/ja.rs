@@ -0,0 +1,131 @@
+use super::{Catalog, Text};
+use crate::template::TemplateKey;
+use crate::train::Status;
+use crate::Expac;
+
+pub(super) const CATALOG: Catalog = Catalog {
+    text,
+    status,
+    expac,
+    template,
+    template_name,
+};
+
+fn text(text: Text) -> &'static str {
+    use Text::*;
+    match text {
+        DashboardTitle => "トレイン ダッシュボード",
+        OverviewTitle => "トレイン概要",
+        World => "ワールド",
+        Expansion => "拡張",
+        Status => "状態",
+        Ready => "準備完了",
+        NextForced => "次の強制湧き",
+        ScoutedMap => "偵察マップ",
+        Scouted => "偵察済み",
+        MaintenanceStarted => {
+            "サーバーメンテナンスが始まりました。終了までトレインの時刻はリセットされます。"
+        }
+        MaintenanceEnded => "サーバーメンテナンスが終了しました。メンテ明けのモブが湧いています。",
+        Schedule => "予定",
+        Conductor => "主催者",
+        StaleScout => "古い偵察",
+        NoUpdateSince => "最終更新",
+        MarksLeft => "残りモブ",
+        SRankDashboardTitle => "Sランク ダッシュボード",
+        SRankNote => "🕑 湧き時間帯の開始 · 🟢 湧き時間帯、最遅の湧きまで · 🔥 最遅の湧きを過ぎています。湧き条件を確認してください",
+        SRankKilled => "{world}の{zone}のSランクが{kill_time}に討伐されました。湧き時間帯は{opens}に始まります",
+        Zone => "エリア",
+        AlertSoon => "直前",
+        AlertForced => "強制湧き",
+        AlertSetSoon => "トレインの強制湧きの{minutes}分前に、ここへアラートを投稿します。",
+        AlertSetForced => "トレインの強制湧き時に、ここへアラートを投稿します。",
+        AlertPing => "{role} をメンションします。",
+        AlertRemoved => "`{kind}` アラートはここに投稿されなくなりました。",
+        AlertListTitle => "強制湧きアラート",
+        AlertListEmpty => "投稿されるアラートはありません。",
+        AlertListSoon => "`{kind}`（{minutes}分前）{channel}",
+        AlertListForced => "`{kind}` {channel}",
+        AlertListPing => "、{role} をメンション",
+        AlertSubscribed => "{roles} でメンションされるようになりました。",
+        AlertUnsubscribed => "{roles} でメンションされなくなりました。",
+        MonitorsUpdated => "{reply}。",
+        MonitorsNotUpdated => "エラー: {reply}。ただし、一部のモニター投稿を更新できませんでした。",
+        GridEmpty => "表示する項目はありません。",
+        GridOverflow => "この投稿に収まりきらないため、一部の項目を省略しました。",
+        ThemeTitle => "トレインのテーマ",
+        ThemeDefaultColour => "既定の色",
+        ThemeUpdated => "{status} のテーマを更新しました",
+        ThemeStatusReset => "{status} のテーマをリセットしました",
+        ThemeReset => "テーマをリセットしました",
+    }
+}
+
+fn status(status: Status) -> &'static str {
+    use Status::*;
+    match status {
+        Unknown => "不明",
+        Waiting => "待機中",
+        Scouted => "偵察済み",
+        Running => "進行中",
+        Maintenance => "メンテ明け湧き",
+        Sniped => "横取り",
+        Partial => "一部討伐",
+        Abandoned => "中止",
+    }
+}
+
+fn expac(expac: Expac) -> &'static str {
+    use Expac::*;
+    match expac {
+        EW => "暁月のフィナーレ",
+        ShB => "漆黒のヴィランズ",
+        StB => "紅蓮のリベレーター",
+        HW => "蒼天のイシュガルド",
+        ARR => "新生エオルゼア",
+    }
+}
+
+fn template(key: TemplateKey) -> &'static str {
+    use TemplateKey::*;
+    match key {
+        MonitorTitle => "{world} {expac} トレイン",
+        MonitorStatus => "{emoji} {status}",
+        MonitorLastRun => "前回の完了: {last_run}",
+        MonitorForce => "強制湧き {force_time}",
+        DashboardNote => "時刻は強制湧き（上限）の時刻を示します。",
+        OverviewNote => {
+            "準備完了のトレインは、最終更新の時点で強制湧き（上限）の時刻を過ぎています。"
+        }
+        Scouted => "{world} {expac} トレインは{scouted}です",
+        Started => "{world} {expac} トレインが出発しました",
+        Completed => "{world} {expac} トレインは {last_run} に完了しました",
+        Reminder => "⏰ {conductor} {world} {expac} トレインが{start_time}に出発します\n{notes}",
+        ForceSoon => "{emoji} {world} {expac} トレインは{force_time}に強制湧きします",
+        Forced => "{emoji} {world} {expac} トレインが強制湧きしました（{force_time}）",
+        Sniped => "{emoji} {world} {expac} トレインは横取りされました。強制湧き {force_time}",
+        Partial => "{world} {expac} トレインは残り{marks_left}体で終了しました",
+        Abandoned => "{world} {expac} トレインは中止されました",
+    }
+}
+
+fn template_name(key: TemplateKey) -> &'static str {
+    use TemplateKey::*;
+    match key {
+        MonitorTitle => "モニターのタイトル",
+        MonitorStatus => "モニターの状態",
+        MonitorLastRun => "モニターの前回の完了",
+        MonitorForce => "モニターの強制湧き",
+        DashboardNote => "ダッシュボードの注記",
+        OverviewNote => "概要の注記",
+        Scouted => "偵察の返信",
+        Started => "出発の返信",
+        Completed => "完了の返信",
+        Reminder => "リマインダー",
+        ForceSoon => "強制湧き直前のアラート",
+        Forced => "強制湧きのアラート",
+        Sniped => "横取りの返信",
+        Partial => "一部討伐の返信",
+        Abandoned => "中止の返信",
+    }
+}
@@ -0,0 +1,21 @@
+use chrono::{DateTime, Utc};
+use sea_orm::entity::prelude::*;
+
+/// A planned server maintenance, applied to every train by the scheduler.
+#[derive(Clone, Debug, DeriveEntityModel)]
+#[sea_orm(table_name = "maintenance_windows")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i64,
+    pub start_time: DateTime<Utc>,
+    pub end_time: DateTime<Utc>,
+    /// Whether the trains have been put into maintenance.
+    pub started: bool,
+    /// Whether the post-maintenance spawns have been set.
+    pub ended: bool,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,191 @@
+use chrono::{DateTime, Duration, Utc};
+use poise::serenity_prelude as serenity;
+use poise::SlashArgument;
+
+use super::{lookup, matching, Expac, LookupError};
+
+/// An A-rank hunt mark, which respawns independently of the others on its
+/// world.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Mark {
+    pub name: &'static str,
+    pub expac: Expac,
+}
+
+const fn mark(name: &'static str, expac: Expac) -> Mark {
+    Mark { name, expac }
+}
+
+/// Every A-rank mark, in the order a train usually visits them.
+pub const A_RANKS: &[Mark] = &[
+    // One per zone.
+    mark("Forneus", Expac::ARR),
+    mark("Melt", Expac::ARR),
+    mark("Ghede Ti Malice", Expac::ARR),
+    mark("Girtab", Expac::ARR),
+    mark("Alectryon", Expac::ARR),
+    mark("Sabotender Bailarina", Expac::ARR),
+    mark("Maahes", Expac::ARR),
+    mark("Zanig'oh", Expac::ARR),
+    mark("Dalvag's Final Flame", Expac::ARR),
+    mark("Marberry", Expac::ARR),
+    mark("Hellsclaw", Expac::ARR),
+    mark("Unktehi", Expac::ARR),
+    mark("Nahn", Expac::ARR),
+    mark("Cornu", Expac::ARR),
+    mark("Vogaal Ja", Expac::ARR),
+    mark("Marraco", Expac::ARR),
+    mark("Kurrea", Expac::ARR),
+    // Two per zone from here on.
+    mark("Mirka", Expac::HW),
+    mark("Lyuba", Expac::HW),
+    mark("Enkelados", Expac::HW),
+    mark("Sisiutl", Expac::HW),
+    mark("Campacti", Expac::HW),
+    mark("Stench Blossom", Expac::HW),
+    mark("Pylraster", Expac::HW),
+    mark("Lord of the Wyverns", Expac::HW),
+    mark("Bune", Expac::HW),
+    mark("Agathos", Expac::HW),
+    mark("Slipkinx Steeljoints", Expac::HW),
+    mark("Stolas", Expac::HW),
+    mark("Orcus", Expac::StB),
+    mark("Erle", Expac::StB),
+    mark("Vochstein", Expac::StB),
+    mark("Aqrabuamelu", Expac::StB),
+    mark("Mahisha", Expac::StB),
+    mark("Luminare", Expac::StB),
+    mark("Funa Yurei", Expac::StB),
+    mark("Oni Yumemi", Expac::StB),
+    mark("Angada", Expac::StB),
+    mark("Gajasura", Expac::StB),
+    mark("Girimekhala", Expac::StB),
+    mark("Sum", Expac::StB),
+    mark("Nariphon", Expac::ShB),
+    mark("Nuckelavee", Expac::ShB),
+    mark("Li'l Murderer", Expac::ShB),
+    mark("Huracan", Expac::ShB),
+    mark("Maliktender", Expac::ShB),
+    mark("Sugaar", Expac::ShB),
+    mark("The Mudman", Expac::ShB),
+    mark("O Poorest Pauldia", Expac::ShB),
+    mark("Grassman", Expac::ShB),
+    mark("Supay", Expac::ShB),
+    mark("Rusalka", Expac::ShB),
+    mark("Baal", Expac::ShB),
+    mark("Hulder", Expac::EW),
+    mark("Storsie", Expac::EW),
+    mark("Yilan", Expac::EW),
+    mark("Sugriva", Expac::EW),
+    mark("Minerva", Expac::EW),
+    mark("Aegeiros", Expac::EW),
+    mark("Mousse Princess", Expac::EW),
+    mark("Lunatender Queen", Expac::EW),
+    mark("Petalodus", Expac::EW),
+    mark("Gurangatch", Expac::EW),
+    mark("Arch-Eta", Expac::EW),
+    mark("Fan Ail", Expac::EW),
+];
+
+/// Where a mark is in its respawn cycle.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Window {
+    /// Not killed since it was last tracked.
+    Unknown,
+    /// Killed too recently to have respawned.
+    Closed { opens: DateTime<Utc> },
+    /// May have respawned, and will have by the force time.
+    Open { forced: DateTime<Utc> },
+    /// Certain to have respawned.
+    Forced { since: DateTime<Utc> },
+}
+
+impl Window {
+    /// Where a mark killed at `killed`, which respawns between `min` and
+    /// `max` later, is in its respawn cycle as of `now`.
+    pub fn at(
+        killed: Option<DateTime<Utc>>,
+        min: Duration,
+        max: Duration,
+        now: DateTime<Utc>,
+    ) -> Self {
+        let Some(killed) = killed else {
+            return Window::Unknown;
+        };
+        let opens = killed + min;
+        let forced = killed + max;
+        if now < opens {
+            Window::Closed { opens }
+        } else if now < forced {
+            Window::Open { forced }
+        } else {
+            Window::Forced { since: forced }
+        }
+    }
+}
+
+impl Mark {
+    /// The shortest time a mark takes to respawn after being killed.
+    pub fn min_respawn() -> Duration {
+        Duration::hours(4)
+    }
+
+    /// The longest time a mark takes to respawn, when it is forced.
+    pub fn max_respawn() -> Duration {
+        Duration::hours(6)
+    }
+
+    pub fn of_expac(expac: Expac) -> impl Iterator<Item = Mark> {
+        A_RANKS.iter().copied().filter(move |m| m.expac == expac)
+    }
+
+    pub fn matching(input: &str) -> Vec<Self> {
+        matching(input, A_RANKS.iter().copied(), |mark| {
+            vec![mark.name.to_owned()]
+        })
+    }
+
+    /// The mark named by `input`, which may be any unambiguous prefix.
+    pub fn lookup(input: &str) -> Result<Self, LookupError> {
+        lookup("mark", input, Self::matching(input))
+    }
+
+    /// Where a mark killed at `killed` is in its respawn cycle as of `now`.
+    pub fn window(killed: Option<DateTime<Utc>>, now: DateTime<Utc>) -> Window {
+        Window::at(killed, Self::min_respawn(), Self::max_respawn(), now)
+    }
+}
+
+impl std::fmt::Display for Mark {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name)
+    }
+}
+
+#[poise::async_trait]
+impl SlashArgument for Mark {
+    async fn extract(
+        _ctx: &serenity::Context,
+        _interaction: poise::ApplicationCommandOrAutocompleteInteraction<'_>,
+        value: &poise::serenity_prelude::json::Value,
+    ) -> Result<Self, poise::SlashArgError> {
+        let input = value
+            .as_str()
+            .ok_or(poise::SlashArgError::CommandStructureMismatch(
+                "expected string",
+            ))?;
+        Self::lookup(input).map_err(|e| poise::SlashArgError::Parse {
+            error: Box::new(e),
+            input: input.to_owned(),
+        })
+    }
+
+    fn create(builder: &mut serenity::CreateApplicationCommandOption) {
+        builder.kind(poise::serenity_prelude::CommandOptionType::String);
+    }
+
+    fn choices() -> Vec<poise::CommandParameterChoice> {
+        // There are too many marks for choices; they are autocompleted instead.
+        Vec::new()
+    }
+}
@@ -0,0 +1,98 @@
+use chrono::{DateTime, Utc};
+use sea_orm::entity::prelude::*;
+use sea_orm::{ConnectionTrait, NotSet, Set};
+use std::collections::HashMap;
+
+use super::mark::Mark;
+use super::{Expac, World};
+
+/// When an A-rank mark was last killed on a world.
+#[derive(Clone, Debug, DeriveEntityModel)]
+#[sea_orm(table_name = "mark_kills")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i64,
+    pub world: World,
+    /// Name of the mark, as in `mark::A_RANKS`.
+    pub mark: String,
+    pub killed_at: DateTime<Utc>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+/// The last kill of every tracked mark of a train, by mark name.
+pub async fn for_train(
+    db: &impl ConnectionTrait,
+    world: World,
+    expac: Expac,
+) -> Result<HashMap<&'static str, DateTime<Utc>>, DbErr> {
+    let kills = Entity::find()
+        .filter(Column::World.eq(world))
+        .all(db)
+        .await?;
+    Ok(Mark::of_expac(expac)
+        .filter_map(|mark| {
+            let kill = kills.iter().find(|k| k.mark == mark.name)?;
+            Some((mark.name, kill.killed_at))
+        })
+        .collect())
+}
+
+/// The latest kill of any of a train's marks after `since`.
+pub async fn latest(
+    db: &impl ConnectionTrait,
+    world: World,
+    expac: Expac,
+    since: DateTime<Utc>,
+) -> Result<Option<DateTime<Utc>>, DbErr> {
+    let kills = for_train(db, world, expac).await?;
+    Ok(kills.into_values().filter(|&t| t > since).max())
+}
+
+/// Records that `mark` was killed on `world` at `killed_at`.
+pub async fn record(
+    db: &impl ConnectionTrait,
+    world: World,
+    mark: Mark,
+    killed_at: DateTime<Utc>,
+) -> Result<(), DbErr> {
+    let existing = Entity::find()
+        .filter(Column::World.eq(world))
+        .filter(Column::Mark.eq(mark.name))
+        .one(db)
+        .await?;
+    let mut kill = match existing {
+        Some(existing) => ActiveModel::from(existing),
+        None => ActiveModel {
+            id: NotSet,
+            world: Set(world),
+            mark: Set(mark.name.to_owned()),
+            ..Default::default()
+        },
+    };
+    kill.killed_at = Set(killed_at);
+    kill.save(db).await?;
+    Ok(())
+}
+
+/// Records every mark of a train as killed at `killed_at`, apart from those
+/// already reported killed at or after `since`, e.g. during the run.
+pub async fn record_train(
+    db: &impl ConnectionTrait,
+    world: World,
+    expac: Expac,
+    since: DateTime<Utc>,
+    killed_at: DateTime<Utc>,
+) -> Result<(), DbErr> {
+    let kills = for_train(db, world, expac).await?;
+    for mark in Mark::of_expac(expac) {
+        if kills.get(mark.name).is_some_and(|&t| t >= since) {
+            continue;
+        }
+        record(db, world, mark, killed_at).await?;
+    }
+    Ok(())
+}
@@ -8,6 +8,10 @@ pub struct Model {
     pub message_id: i64,
     pub channel_id: i64,
     pub train_id: i64,
+    /// Hash of the content last posted, see `Post::hash`.
+    pub content_hash: Option<i64>,
+    /// Whether to post a new message if this one is deleted.
+    pub sticky: bool,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -0,0 +1,16 @@
+use sea_orm::entity::prelude::*;
+
+/// A channel that scheduled events, such as maintenance, are announced in.
+#[derive(Clone, Debug, DeriveEntityModel)]
+#[sea_orm(table_name = "notice_channels")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i64,
+    #[sea_orm(unique)]
+    pub channel_id: i64,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
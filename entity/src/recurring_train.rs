@@ -0,0 +1,60 @@
+use chrono::{DateTime, Duration, Utc};
+use sea_orm::entity::prelude::*;
+
+/// A rule for planning the same train every week or every few weeks. Its
+/// occurrences are planned as `scheduled_train`s ahead of time.
+#[derive(Clone, Debug, DeriveEntityModel)]
+#[sea_orm(table_name = "recurring_trains")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i64,
+    pub train_id: i64,
+    /// Start of the first occurrence; the others follow at the same time of
+    /// week.
+    pub start_time: DateTime<Utc>,
+    pub interval_weeks: i64,
+    pub conductor: i64,
+    pub notes: Option<String>,
+    pub remind_minutes: i64,
+    pub channel_id: i64,
+    /// Shown on the Discord events of the occurrences.
+    pub location: Option<String>,
+    /// Paused rules plan no further occurrences.
+    pub paused: bool,
+    /// Start of the last occurrence planned, if any has been.
+    pub planned_until: Option<DateTime<Utc>>,
+}
+
+impl Model {
+    pub fn period(&self) -> Duration {
+        Duration::weeks(self.interval_weeks)
+    }
+
+    /// Start of the first occurrence after `after`.
+    pub fn next_after(&self, after: DateTime<Utc>) -> DateTime<Utc> {
+        if after < self.start_time {
+            return self.start_time;
+        }
+        let period = self.period().num_seconds();
+        let elapsed = (after - self.start_time).num_seconds();
+        self.start_time + Duration::seconds((elapsed / period + 1) * period)
+    }
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::train::Entity",
+        from = "Column::TrainId",
+        to = "super::train::Column::Id"
+    )]
+    Train,
+}
+
+impl Related<super::train::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Train.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,205 @@
+use chrono::{DateTime, Duration, Utc};
+use poise::serenity_prelude as serenity;
+use poise::SlashArgument;
+use sea_orm::entity::prelude::*;
+use sea_orm::{ConnectionTrait, NotSet, Set};
+
+use super::mark::Window;
+use super::{lookup, matching, Expac, LookupError, World};
+
+/// The S-rank mark of one zone on one world.
+#[derive(Clone, Debug, DeriveEntityModel)]
+#[sea_orm(table_name = "s_ranks")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i64,
+    pub world: World,
+    /// Name of the zone, as in `ZONES`.
+    pub zone: String,
+    pub last_kill: Option<DateTime<Utc>>,
+    /// Hours after a kill before the mark can spawn again.
+    pub min_hours: i64,
+    /// Hours after a kill by which the mark can certainly spawn again.
+    pub max_hours: i64,
+    /// What has to be done for the mark to spawn.
+    pub notes: Option<String>,
+}
+
+impl Model {
+    pub fn window(&self, now: DateTime<Utc>) -> Window {
+        Window::at(
+            self.last_kill,
+            Duration::hours(self.min_hours),
+            Duration::hours(self.max_hours),
+            now,
+        )
+    }
+
+    /// When the window last opened or closed, or next will.
+    pub fn window_changes(&self) -> Vec<DateTime<Utc>> {
+        match self.last_kill {
+            Some(kill) => vec![
+                kill + Duration::hours(self.min_hours),
+                kill + Duration::hours(self.max_hours),
+            ],
+            None => Vec::new(),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+/// A zone with an S-rank mark.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Zone {
+    pub name: &'static str,
+    pub expac: Expac,
+}
+
+const fn zone(name: &'static str, expac: Expac) -> Zone {
+    Zone { name, expac }
+}
+
+/// Every zone with an S-rank mark.
+pub const ZONES: &[Zone] = &[
+    zone("Middle La Noscea", Expac::ARR),
+    zone("Lower La Noscea", Expac::ARR),
+    zone("Eastern La Noscea", Expac::ARR),
+    zone("Western La Noscea", Expac::ARR),
+    zone("Upper La Noscea", Expac::ARR),
+    zone("Outer La Noscea", Expac::ARR),
+    zone("Central Shroud", Expac::ARR),
+    zone("East Shroud", Expac::ARR),
+    zone("South Shroud", Expac::ARR),
+    zone("North Shroud", Expac::ARR),
+    zone("Western Thanalan", Expac::ARR),
+    zone("Central Thanalan", Expac::ARR),
+    zone("Eastern Thanalan", Expac::ARR),
+    zone("Southern Thanalan", Expac::ARR),
+    zone("Northern Thanalan", Expac::ARR),
+    zone("Coerthas Central Highlands", Expac::ARR),
+    zone("Mor Dhona", Expac::ARR),
+    zone("Coerthas Western Highlands", Expac::HW),
+    zone("The Sea of Clouds", Expac::HW),
+    zone("Azys Lla", Expac::HW),
+    zone("The Dravanian Forelands", Expac::HW),
+    zone("The Dravanian Hinterlands", Expac::HW),
+    zone("The Churning Mists", Expac::HW),
+    zone("The Fringes", Expac::StB),
+    zone("The Peaks", Expac::StB),
+    zone("The Lochs", Expac::StB),
+    zone("The Ruby Sea", Expac::StB),
+    zone("Yanxia", Expac::StB),
+    zone("The Azim Steppe", Expac::StB),
+    zone("Lakeland", Expac::ShB),
+    zone("Kholusia", Expac::ShB),
+    zone("Amh Araeng", Expac::ShB),
+    zone("Il Mheg", Expac::ShB),
+    zone("The Rak'tika Greatwood", Expac::ShB),
+    zone("The Tempest", Expac::ShB),
+    zone("Labyrinthos", Expac::EW),
+    zone("Thavnair", Expac::EW),
+    zone("Garlemald", Expac::EW),
+    zone("Mare Lamentorum", Expac::EW),
+    zone("Ultima Thule", Expac::EW),
+    zone("Elpis", Expac::EW),
+];
+
+impl Zone {
+    /// The respawn window, in hours, of S-ranks that have not been given one
+    /// of their own.
+    pub fn default_hours(&self) -> (i64, i64) {
+        match self.expac {
+            Expac::ARR => (46, 70),
+            _ => (84, 132),
+        }
+    }
+
+    pub fn matching(input: &str) -> Vec<Self> {
+        matching(input, ZONES.iter().copied(), |zone| {
+            let mut names = vec![zone.name.to_owned()];
+            // Most zones are commonly named without the article.
+            if let Some(name) = zone.name.strip_prefix("The ") {
+                names.push(name.to_owned());
+            }
+            names
+        })
+    }
+
+    /// The zone named by `input`, which may be any unambiguous prefix.
+    pub fn lookup(input: &str) -> Result<Self, LookupError> {
+        lookup("zone", input, Self::matching(input))
+    }
+
+    /// The zone named `name` exactly, as stored in the DB.
+    pub fn by_name(name: &str) -> Option<Self> {
+        ZONES.iter().copied().find(|zone| zone.name == name)
+    }
+}
+
+impl std::fmt::Display for Zone {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name)
+    }
+}
+
+#[poise::async_trait]
+impl SlashArgument for Zone {
+    async fn extract(
+        _ctx: &serenity::Context,
+        _interaction: poise::ApplicationCommandOrAutocompleteInteraction<'_>,
+        value: &poise::serenity_prelude::json::Value,
+    ) -> Result<Self, poise::SlashArgError> {
+        let input = value
+            .as_str()
+            .ok_or(poise::SlashArgError::CommandStructureMismatch(
+                "expected string",
+            ))?;
+        Self::lookup(input).map_err(|e| poise::SlashArgError::Parse {
+            error: Box::new(e),
+            input: input.to_owned(),
+        })
+    }
+
+    fn create(builder: &mut serenity::CreateApplicationCommandOption) {
+        builder.kind(poise::serenity_prelude::CommandOptionType::String);
+    }
+
+    fn choices() -> Vec<poise::CommandParameterChoice> {
+        // There are too many zones for choices; they are autocompleted instead.
+        Vec::new()
+    }
+}
+
+/// The S-rank of `zone` on `world`, with the default window if it has not
+/// been tracked before.
+pub async fn find_or_create(
+    db: &impl ConnectionTrait,
+    world: World,
+    zone: Zone,
+) -> Result<Model, DbErr> {
+    let existing = Entity::find()
+        .filter(Column::World.eq(world))
+        .filter(Column::Zone.eq(zone.name))
+        .one(db)
+        .await?;
+    match existing {
+        Some(existing) => Ok(existing),
+        None => {
+            let (min_hours, max_hours) = zone.default_hours();
+            let new = ActiveModel {
+                id: NotSet,
+                world: Set(world),
+                zone: Set(zone.name.to_owned()),
+                last_kill: Set(None),
+                min_hours: Set(min_hours),
+                max_hours: Set(max_hours),
+                notes: Set(None),
+            };
+            new.insert(db).await
+        }
+    }
+}
@@ -0,0 +1,38 @@
+use sea_orm::entity::prelude::*;
+
+use super::dashboard::filter_includes;
+use super::s_rank::Zone;
+use super::World;
+
+/// A post showing the S-rank windows of several worlds.
+#[derive(Clone, Debug, DeriveEntityModel)]
+#[sea_orm(table_name = "s_rank_dashboards")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i64,
+    pub message_id: i64,
+    pub channel_id: i64,
+    /// Hash of the content last posted, see `Post::hash`.
+    pub content_hash: Option<i64>,
+    /// Whether to post a new message if this one is deleted.
+    pub sticky: bool,
+    /// Comma-separated worlds to show, or all worlds if unset.
+    pub worlds: Option<String>,
+    /// Comma-separated expansions to show, or all expansions if unset.
+    pub expacs: Option<String>,
+}
+
+impl Model {
+    pub fn shows_world(&self, world: World) -> bool {
+        world.data_center().is_some() && filter_includes(&self.worlds, world)
+    }
+
+    pub fn shows_zone(&self, zone: Zone) -> bool {
+        filter_includes(&self.expacs, zone.expac)
+    }
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,101 @@
+use chrono::{DateTime, Duration, Utc};
+use sea_orm::entity::prelude::*;
+use sea_orm::{ConnectionTrait, QueryOrder};
+
+/// How long after its start time a planned train that has not been started
+/// is still shown and can be linked to a run.
+pub fn grace() -> Duration {
+    Duration::hours(1)
+}
+
+/// A train a conductor plans to run.
+#[derive(Clone, Debug, DeriveEntityModel)]
+#[sea_orm(table_name = "scheduled_trains")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i64,
+    pub train_id: i64,
+    pub start_time: DateTime<Utc>,
+    /// Discord user who will conduct the train.
+    pub conductor: i64,
+    pub notes: Option<String>,
+    /// How many minutes before the start to remind the conductor.
+    pub remind_minutes: i64,
+    /// Channel that the reminder is posted in.
+    pub channel_id: i64,
+    pub reminded: bool,
+    /// When the train was started with `/train start`, if it has been.
+    pub started_at: Option<DateTime<Utc>>,
+    /// Discord scheduled event mirroring the planned train, until the event
+    /// has been completed or cancelled.
+    pub event_id: Option<i64>,
+    /// The `recurring_train` this is an occurrence of, if any.
+    pub series_id: Option<i64>,
+}
+
+impl Model {
+    pub fn remind_time(&self) -> DateTime<Utc> {
+        self.start_time - Duration::minutes(self.remind_minutes)
+    }
+
+    /// Whether the train was neither started nor run within the grace period.
+    pub fn lapsed(&self, now: DateTime<Utc>) -> bool {
+        self.started_at.is_none() && self.start_time + grace() <= now
+    }
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::train::Entity",
+        from = "Column::TrainId",
+        to = "super::train::Column::Id"
+    )]
+    Train,
+}
+
+impl Related<super::train::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Train.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+/// Planned trains that have not been started and have not lapsed, soonest
+/// first.
+pub fn upcoming(now: DateTime<Utc>) -> Select<Entity> {
+    Entity::find()
+        .filter(Column::StartedAt.is_null())
+        .filter(Column::StartTime.gt(now - grace()))
+        .order_by_asc(Column::StartTime)
+}
+
+/// The planned run of `train_id` that a start at `now` belongs to: the
+/// earliest upcoming one that is due within the grace period.
+pub async fn due(
+    db: &impl ConnectionTrait,
+    train_id: i64,
+    now: DateTime<Utc>,
+) -> Result<Option<Model>, DbErr> {
+    upcoming(now)
+        .filter(Column::TrainId.eq(train_id))
+        .filter(Column::StartTime.lte(now + grace()))
+        .one(db)
+        .await
+}
+
+/// Links the planned run of `train_id` that a start at `now` belongs to, if
+/// there is one, to the start.
+pub async fn link_run(
+    db: &impl ConnectionTrait,
+    train_id: i64,
+    now: DateTime<Utc>,
+) -> Result<Option<Model>, DbErr> {
+    let Some(scheduled) = due(db, train_id, now).await? else {
+        return Ok(None);
+    };
+    let mut scheduled = ActiveModel::from(scheduled);
+    scheduled.started_at = sea_orm::Set(Some(now));
+    Ok(Some(scheduled.update(db).await?))
+}
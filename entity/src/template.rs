@@ -0,0 +1,407 @@
+use poise::serenity_prelude as serenity;
+use poise::SlashArgument;
+use sea_orm::entity::prelude::*;
+use sea_orm::sea_query::{self, SeaRc};
+use sea_orm::{ConnectionTrait, Iterable};
+use std::collections::HashMap;
+use strum_macros::{AsRefStr, Display, EnumString};
+
+use super::locale::{choice_localizations, Locale};
+use super::theme::Theme;
+use super::{scheduled_train, train};
+
+/// Longest template accepted for titles; embed titles are limited to 256
+/// characters once the variables are filled in.
+const MAX_TITLE_LEN: usize = 200;
+/// Longest template accepted for anything else.
+const MAX_LEN: usize = 400;
+
+/// A guild's wording for one piece of text.
+#[derive(Clone, Debug, DeriveEntityModel)]
+#[sea_orm(table_name = "templates")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i64,
+    pub guild_id: i64,
+    pub key: TemplateKey,
+    pub text: String,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+/// Every piece of text that can be reworded.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Display, Debug, EnumIter, EnumString, AsRefStr)]
+#[strum(serialize_all = "snake_case")]
+pub enum TemplateKey {
+    MonitorTitle,
+    MonitorStatus,
+    MonitorLastRun,
+    MonitorForce,
+    DashboardNote,
+    OverviewNote,
+    Scouted,
+    Started,
+    Completed,
+    Reminder,
+    ForceSoon,
+    Forced,
+    Sniped,
+    Partial,
+    Abandoned,
+}
+
+const TRAIN_VARS: &[&str] = &[
+    "world",
+    "expac",
+    "status",
+    "emoji",
+    "conductor",
+    "map",
+    "last_run",
+    "force_time",
+];
+/// `conductor` is whoever will conduct the planned train.
+const SCHEDULE_VARS: &[&str] = &[
+    "world",
+    "expac",
+    "status",
+    "emoji",
+    "conductor",
+    "map",
+    "last_run",
+    "force_time",
+    "start_time",
+    "notes",
+];
+/// `scouted` is the word "scouted", linked to the map if there is one.
+const SCOUTED_VARS: &[&str] = &[
+    "world",
+    "expac",
+    "status",
+    "emoji",
+    "conductor",
+    "map",
+    "last_run",
+    "force_time",
+    "scouted",
+];
+/// `marks_left` is how many marks the partial run left alive.
+const PARTIAL_VARS: &[&str] = &[
+    "world",
+    "expac",
+    "status",
+    "emoji",
+    "conductor",
+    "map",
+    "last_run",
+    "force_time",
+    "marks_left",
+];
+
+impl TemplateKey {
+    /// What the text is used for.
+    pub fn description(&self) -> &'static str {
+        use TemplateKey::*;
+        match self {
+            MonitorTitle => "Title of a monitor",
+            MonitorStatus => "First line of a monitor",
+            MonitorLastRun => "Monitor line shown once a train has been run",
+            MonitorForce => "Monitor line shown while waiting for the force time",
+            DashboardNote => "Text above a dashboard",
+            OverviewNote => "Text above an overview dashboard",
+            Scouted => "Reply to /train scout",
+            Started => "Reply to /train start",
+            Completed => "Reply to /train done",
+            Reminder => "Reminder posted before a planned train",
+            ForceSoon => "Alert line for a train that will be forced soon",
+            Forced => "Alert line for a train that has just been forced",
+            Sniped => "Reply to /train sniped",
+            Partial => "Reply to /train abandon when marks are left",
+            Abandoned => "Reply to /train abandon",
+        }
+    }
+
+    /// Names of the variables available to the template.
+    pub fn variables(&self) -> &'static [&'static str] {
+        use TemplateKey::*;
+        match self {
+            DashboardNote | OverviewNote => &[],
+            Scouted => SCOUTED_VARS,
+            Reminder => SCHEDULE_VARS,
+            Partial => PARTIAL_VARS,
+            _ => TRAIN_VARS,
+        }
+    }
+
+    fn max_len(&self) -> usize {
+        match self {
+            TemplateKey::MonitorTitle => MAX_TITLE_LEN,
+            _ => MAX_LEN,
+        }
+    }
+
+    /// Checks that `text` can be used for this key, describing the problem if not.
+    pub fn validate(&self, text: &str) -> Result<(), String> {
+        if text.trim().is_empty() {
+            return Err("Template cannot be empty".to_owned());
+        }
+        if text.chars().count() > self.max_len() {
+            return Err(format!(
+                "Template is too long (at most {} characters)",
+                self.max_len()
+            ));
+        }
+        for piece in parse(text)? {
+            if let Piece::Var(name) = piece {
+                if !self.variables().contains(&name) {
+                    return Err(format!("Unknown variable `{{{}}}` for {}", name, self));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[poise::async_trait]
+impl SlashArgument for TemplateKey {
+    async fn extract(
+        _ctx: &serenity::Context,
+        _interaction: poise::ApplicationCommandOrAutocompleteInteraction<'_>,
+        value: &poise::serenity_prelude::json::Value,
+    ) -> Result<Self, poise::SlashArgError> {
+        let choice = value
+            .as_u64()
+            .ok_or(poise::SlashArgError::CommandStructureMismatch(
+                "expected u64",
+            ))?;
+        Self::iter()
+            // TODO: technically this can overflow on 32-bit systems
+            .nth(choice as usize)
+            .ok_or(poise::SlashArgError::CommandStructureMismatch(
+                "argument out of range",
+            ))
+    }
+
+    fn create(builder: &mut serenity::CreateApplicationCommandOption) {
+        builder.kind(poise::serenity_prelude::CommandOptionType::Integer);
+    }
+
+    fn choices() -> Vec<poise::CommandParameterChoice> {
+        Self::iter()
+            .map(|key| poise::CommandParameterChoice {
+                name: key.to_string(),
+                localizations: choice_localizations(|locale| locale.template_name(key).to_owned()),
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Iden)]
+pub struct TemplateKeyEnum;
+
+impl ActiveEnum for TemplateKey {
+    type Value = String;
+
+    fn name() -> sea_orm::DynIden {
+        SeaRc::new(TemplateKeyEnum)
+    }
+
+    fn to_value(&self) -> Self::Value {
+        self.to_string()
+    }
+
+    fn try_from_value(v: &Self::Value) -> Result<Self, DbErr> {
+        v.parse::<Self>().map_err(|e| DbErr::Type(e.to_string()))
+    }
+
+    fn db_type() -> ColumnDef {
+        ColumnType::String(None).def()
+    }
+}
+
+impl From<TemplateKey> for sea_orm::Value {
+    fn from(key: TemplateKey) -> Self {
+        key.into_value().into()
+    }
+}
+
+impl sea_orm::TryGetable for TemplateKey {
+    fn try_get(res: &QueryResult, pre: &str, col: &str) -> Result<Self, sea_orm::TryGetError> {
+        let value = <<Self as ActiveEnum>::Value as sea_orm::TryGetable>::try_get(res, pre, col)?;
+        <Self as sea_orm::ActiveEnum>::try_from_value(&value).map_err(sea_orm::TryGetError::DbErr)
+    }
+}
+
+impl sea_query::ValueType for TemplateKey {
+    fn try_from(v: Value) -> Result<Self, sea_query::ValueTypeErr> {
+        let value =
+            <<Self as sea_orm::ActiveEnum>::Value as sea_orm::sea_query::ValueType>::try_from(v)?;
+        <Self as sea_orm::ActiveEnum>::try_from_value(&value)
+            .map_err(|_| sea_orm::sea_query::ValueTypeErr)
+    }
+
+    fn type_name() -> String {
+        <<Self as sea_orm::ActiveEnum>::Value as sea_orm::sea_query::ValueType>::type_name()
+    }
+
+    fn array_type() -> sea_query::ArrayType {
+        unimplemented!("Array of enum is not supported.")
+    }
+
+    fn column_type() -> sea_query::ColumnType {
+        <Self as sea_orm::ActiveEnum>::db_type()
+            .get_column_type()
+            .to_owned()
+            .into()
+    }
+}
+
+/// Values substituted for `{name}` in a template.
+#[derive(Clone, Debug, Default)]
+pub struct Vars(HashMap<&'static str, String>);
+
+impl Vars {
+    /// Everything known about `train`. Variables that do not apply, such as the
+    /// map of a train that has not been scouted, are empty.
+    pub fn train(train: &train::Model, theme: &Theme, locale: Locale) -> Self {
+        let timestamp = |t: Option<chrono::DateTime<chrono::Utc>>, style: char| {
+            t.map(|t| format!("<t:{}:{}>", t.timestamp(), style))
+                .unwrap_or_default()
+        };
+        Vars::default()
+            .with("world", train.world.to_string())
+            .with("expac", locale.expac(train.expac).to_owned())
+            .with("status", locale.status(train.status).to_owned())
+            .with("emoji", theme.emoji(train.status).to_owned())
+            .with(
+                "conductor",
+                train
+                    .conductor
+                    .map(|id| format!("<@{}>", id))
+                    .unwrap_or_default(),
+            )
+            .with("map", train.scout_map.clone().unwrap_or_default())
+            .with("last_run", timestamp(train.last_run, 'f'))
+            .with("force_time", timestamp(train.force_time(), 'R'))
+    }
+
+    /// Everything known about `train`, planned to be run as `scheduled`.
+    pub fn schedule(
+        train: &train::Model,
+        scheduled: &scheduled_train::Model,
+        theme: &Theme,
+        locale: Locale,
+    ) -> Self {
+        Vars::train(train, theme, locale)
+            .with("conductor", format!("<@{}>", scheduled.conductor))
+            .with(
+                "start_time",
+                format!("<t:{}:R>", scheduled.start_time.timestamp()),
+            )
+            .with("notes", scheduled.notes.clone().unwrap_or_default())
+    }
+
+    pub fn with(mut self, name: &'static str, value: String) -> Self {
+        self.0.insert(name, value);
+        self
+    }
+}
+
+/// A guild's templates, falling back to the defaults of a locale.
+#[derive(Clone, Debug, Default)]
+pub struct Templates {
+    locale: Locale,
+    custom: HashMap<TemplateKey, String>,
+}
+
+impl Templates {
+    pub async fn load(
+        db: &impl ConnectionTrait,
+        guild_id: i64,
+        locale: Locale,
+    ) -> Result<Self, DbErr> {
+        let rows = Entity::find()
+            .filter(Column::GuildId.eq(guild_id))
+            .all(db)
+            .await?;
+        Ok(Templates {
+            locale,
+            custom: rows.into_iter().map(|row| (row.key, row.text)).collect(),
+        })
+    }
+
+    pub fn locale(&self) -> Locale {
+        self.locale
+    }
+
+    /// The text of the template, without any variables filled in.
+    pub fn get(&self, key: TemplateKey) -> &str {
+        self.custom
+            .get(&key)
+            .map_or_else(|| self.locale.template(key), String::as_str)
+    }
+
+    pub fn is_custom(&self, key: TemplateKey) -> bool {
+        self.custom.contains_key(&key)
+    }
+
+    pub fn render(&self, key: TemplateKey, vars: &Vars) -> String {
+        render(self.get(key), vars)
+    }
+}
+
+enum Piece<'a> {
+    Text(&'a str),
+    Var(&'a str),
+}
+
+/// Splits a template into literal text and variables. `{{` and `}}` stand for
+/// literal braces.
+fn parse(text: &str) -> Result<Vec<Piece<'_>>, String> {
+    let mut pieces = Vec::new();
+    let mut rest = text;
+    while let Some(i) = rest.find(['{', '}']) {
+        pieces.push(Piece::Text(&rest[..i]));
+        let brace = &rest[i..];
+        if brace.starts_with("{{") || brace.starts_with("}}") {
+            pieces.push(Piece::Text(&brace[..1]));
+            rest = &brace[2..];
+        } else if brace.starts_with('}') {
+            return Err("Unmatched `}`; use `}}` for a literal brace".to_owned());
+        } else {
+            let Some(end) = brace.find('}') else {
+                return Err("Unclosed `{`; use `{{` for a literal brace".to_owned());
+            };
+            pieces.push(Piece::Var(brace[1..end].trim()));
+            rest = &brace[end + 1..];
+        }
+    }
+    pieces.push(Piece::Text(rest));
+    Ok(pieces)
+}
+
+/// Fills in the variables of `template`. Anything that cannot be filled in is
+/// left as it was written.
+pub fn render(template: &str, vars: &Vars) -> String {
+    let Ok(pieces) = parse(template) else {
+        return template.to_owned();
+    };
+    let mut rendered = String::new();
+    for piece in pieces {
+        match piece {
+            Piece::Text(text) => rendered.push_str(text),
+            Piece::Var(name) => match vars.0.get(name) {
+                Some(value) => rendered.push_str(value),
+                None => {
+                    rendered.push('{');
+                    rendered.push_str(name);
+                    rendered.push('}');
+                }
+            },
+        }
+    }
+    rendered
+}
@@ -0,0 +1,61 @@
+use sea_orm::entity::prelude::*;
+use sea_orm::ConnectionTrait;
+use serenity::utils::Colour;
+use std::collections::HashMap;
+
+use super::train::Status;
+
+/// A guild's override of how one `Status` is displayed.
+#[derive(Clone, Debug, DeriveEntityModel)]
+#[sea_orm(table_name = "themes")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i64,
+    pub guild_id: i64,
+    pub status: Status,
+    /// Unicode emoji or custom emoji in `<:name:id>` form.
+    pub emoji: Option<String>,
+    /// Embed colour as 0xRRGGBB.
+    pub colour: Option<i32>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+/// All of a guild's display overrides, falling back to the defaults.
+#[derive(Clone, Debug, Default)]
+pub struct Theme {
+    emoji: HashMap<Status, String>,
+    colours: HashMap<Status, Colour>,
+}
+
+impl Theme {
+    pub async fn load(db: &impl ConnectionTrait, guild_id: i64) -> Result<Self, DbErr> {
+        let mut theme = Theme::default();
+        for row in Entity::find()
+            .filter(Column::GuildId.eq(guild_id))
+            .all(db)
+            .await?
+        {
+            if let Some(emoji) = row.emoji {
+                theme.emoji.insert(row.status, emoji);
+            }
+            if let Some(colour) = row.colour {
+                theme.colours.insert(row.status, Colour(colour as u32));
+            }
+        }
+        Ok(theme)
+    }
+
+    pub fn emoji(&self, status: Status) -> &str {
+        self.emoji
+            .get(&status)
+            .map_or_else(|| status.emoji(), String::as_str)
+    }
+
+    pub fn colour(&self, status: Status) -> Option<Colour> {
+        self.colours.get(&status).copied()
+    }
+}
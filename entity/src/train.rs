@@ -1,12 +1,18 @@
 use chrono::{DateTime, Duration, Utc};
+use poise::serenity_prelude as serenity;
+use poise::SlashArgument;
 use sea_orm::entity::prelude::*;
 use sea_orm::sea_query::{self, SeaRc};
-use sea_orm::{ConnectionTrait, EnumIter, NotSet, Set};
+use sea_orm::{ConnectionTrait, EnumIter, Iterable, NotSet, Set};
 use serenity::builder::{CreateButton, CreateComponents, CreateEmbed};
 use serenity::model::prelude::component::ButtonStyle;
-use std::fmt::Write;
 use strum_macros::{Display, FromRepr};
 
+use super::expiry_rule::{stale_label, STALE_EMOJI};
+use super::locale::{choice_localizations, Locale, Text};
+use super::mark::Mark;
+use super::template::{TemplateKey, Templates, Vars};
+use super::theme::Theme;
 use super::{Expac, World};
 
 #[derive(Clone, Debug, DeriveEntityModel)]
@@ -19,51 +25,169 @@ pub struct Model {
     pub status: Status,
     pub scout_map: Option<String>,
     pub last_run: Option<DateTime<Utc>>,
+    /// Discord user who last started the train.
+    pub conductor: Option<i64>,
+    /// When the train's status was last changed through a command.
+    pub updated_at: Option<DateTime<Utc>>,
+    /// When the server maintenance the train is waiting on ended, if it has.
+    pub maintenance_end: Option<DateTime<Utc>>,
+    /// How many marks a partial run left alive.
+    pub marks_left: Option<i32>,
 }
 
 impl Model {
     /// Clears an existing scout map if None is provided.
     pub fn scout(&mut self, scout_map: Option<String>) {
         self.status = Status::Scouted;
-        self.scout_map = scout_map.map(|u| u.into());
+        self.scout_map = scout_map;
+        self.maintenance_end = None;
+        self.marks_left = None;
+        self.updated_at = Some(Utc::now());
     }
-    pub fn start(&mut self) {
+    pub fn start(&mut self, conductor: i64) {
         self.status = Status::Running;
         self.last_run = None;
+        self.conductor = Some(conductor);
+        self.maintenance_end = None;
+        self.marks_left = None;
+        self.updated_at = Some(Utc::now());
     }
     pub fn done(&mut self, last_run: DateTime<Utc>) {
         self.status = Status::Waiting;
         self.scout_map = None;
         self.last_run = Some(last_run);
+        self.maintenance_end = None;
+        self.marks_left = None;
+        self.updated_at = Some(Utc::now());
+    }
+    /// The marks were killed outside of a train, so a new window starts from
+    /// when they died.
+    pub fn sniped(&mut self, killed: DateTime<Utc>) {
+        self.done(killed);
+        self.status = Status::Sniped;
+    }
+    /// The train stopped early; the marks still alive can be run later, so
+    /// the scout map is kept.
+    pub fn partial(&mut self, last_kill: DateTime<Utc>, marks_left: i32) {
+        self.status = Status::Partial;
+        self.last_run = Some(last_kill);
+        self.maintenance_end = None;
+        self.marks_left = Some(marks_left);
+        self.updated_at = Some(Utc::now());
+    }
+    /// The train was given up before any marks were killed, so they are all
+    /// still alive where they were scouted, and the previous run no longer
+    /// tells when they spawn.
+    pub fn abandon(&mut self) {
+        self.status = Status::Abandoned;
+        self.last_run = None;
+        self.maintenance_end = None;
+        self.marks_left = None;
+        self.updated_at = Some(Utc::now());
+    }
+    /// One of the train's marks was reported killed at `killed`. A kill soon
+    /// after the train was completed belongs to that run, which must really
+    /// have finished then. Returns whether the train changed.
+    pub fn mark_killed(&mut self, killed: DateTime<Utc>) -> bool {
+        match (self.status, self.last_run) {
+            (Status::Waiting | Status::Sniped | Status::Partial, Some(last_run))
+                if killed > last_run
+                    && last_run
+                        .checked_add_signed(Mark::min_respawn())
+                        .is_some_and(|respawn| killed < respawn) =>
+            {
+                self.last_run = Some(killed);
+                true
+            }
+            _ => false,
+        }
     }
     pub fn reset(&mut self) {
         self.status = Status::Unknown;
         self.scout_map = None;
         self.last_run = None;
+        self.conductor = None;
+        self.maintenance_end = None;
+        self.marks_left = None;
+        self.updated_at = Some(Utc::now());
+    }
+    /// Every mark respawns after maintenance, so earlier runs no longer matter.
+    pub fn start_maintenance(&mut self) {
+        self.reset();
+        self.status = Status::Maintenance;
+    }
+    pub fn end_maintenance(&mut self, end: DateTime<Utc>) {
+        self.maintenance_end = Some(end);
+        self.updated_at = Some(Utc::now());
     }
 
-    pub fn format_embed<'a>(&self, embed: &'a mut CreateEmbed) -> &'a mut CreateEmbed {
-        let mut content = format!("{} {}", self.status.emoji(), self.status);
-        if let Some(end_time) = self.last_run {
-            write!(
-                content,
-                "\nLast run completed at: <t:{}:f>",
-                end_time.timestamp()
-            )
-            .unwrap();
-            if self.status == Status::Waiting {
-                let force_time = end_time + (Duration::hours(6));
-                write!(content, "\nForced <t:{}:R>", force_time.timestamp()).unwrap();
+    /// When the marks will be forced to spawn, if the train is waiting on them.
+    /// After a partial run, this is when the marks that were killed are forced.
+    pub fn force_time(&self) -> Option<DateTime<Utc>> {
+        match (self.status, self.last_run) {
+            (Status::Waiting | Status::Sniped | Status::Partial, Some(last_run)) => {
+                last_run.checked_add_signed(Duration::hours(6))
             }
+            // Marks spawn as soon as the servers come back up.
+            (Status::Maintenance, _) => self.maintenance_end,
+            _ => None,
         }
-        embed
-            .title(format!("{} {} Train", self.world, self.expac))
-            .description(content)
+    }
+
+    pub fn format_embed<'a>(
+        &self,
+        embed: &'a mut CreateEmbed,
+        theme: &Theme,
+        templates: &Templates,
+        stale: bool,
+    ) -> &'a mut CreateEmbed {
+        let locale = templates.locale();
+        let mut vars = Vars::train(self, theme, locale);
+        if stale {
+            vars = vars
+                .with("status", stale_label(self.status, locale).to_owned())
+                .with("emoji", STALE_EMOJI.to_owned());
+        }
+        let mut content = templates.render(TemplateKey::MonitorStatus, &vars);
+        if let (true, Some(updated_at)) = (stale, self.updated_at) {
+            content.push_str(&format!(
+                "\n*{} <t:{}:R>*",
+                locale.text(Text::NoUpdateSince),
+                updated_at.timestamp()
+            ));
+        }
+        if let (Status::Partial, Some(marks_left)) = (self.status, self.marks_left) {
+            content.push_str(&format!(
+                "\n{}: {}",
+                locale.text(Text::MarksLeft),
+                marks_left
+            ));
+        }
+        if self.last_run.is_some() {
+            content.push('\n');
+            content.push_str(&templates.render(TemplateKey::MonitorLastRun, &vars));
+        }
+        if self.force_time().is_some() {
+            content.push('\n');
+            content.push_str(&templates.render(TemplateKey::MonitorForce, &vars));
+        }
+        let status = if stale { Status::Unknown } else { self.status };
+        if let Some(colour) = theme.colour(status) {
+            embed.colour(colour);
+        }
+        // Embed titles are limited to 256 characters.
+        let title: String = templates
+            .render(TemplateKey::MonitorTitle, &vars)
+            .chars()
+            .take(256)
+            .collect();
+        embed.title(title).description(content)
     }
 
     pub fn format_components<'a>(
         &self,
         components: &'a mut CreateComponents,
+        locale: Locale,
     ) -> &'a mut CreateComponents {
         /*
         components.create_action_row(|row| {
@@ -89,38 +213,42 @@ impl Model {
         */
         if let Some(url) = &self.scout_map {
             components.create_action_row(|row| {
-                row.create_button(|button| Self::create_scout_link(url, button))
+                row.create_button(|button| Self::create_scout_link(url, locale, button))
             });
         }
         components
     }
 
     #[allow(unused)]
-    fn create_scout_button<'b>(button: &'b mut CreateButton) -> &'b mut CreateButton {
+    fn create_scout_button(button: &mut CreateButton) -> &mut CreateButton {
         button
             .style(ButtonStyle::Primary)
             .label("Scout")
             .custom_id("scout")
     }
     #[allow(unused)]
-    fn create_run_button<'b>(button: &'b mut CreateButton) -> &'b mut CreateButton {
+    fn create_run_button(button: &mut CreateButton) -> &mut CreateButton {
         button
             .style(ButtonStyle::Primary)
             .label("Start")
             .custom_id("run")
     }
     #[allow(unused)]
-    fn create_done_button<'b>(button: &'b mut CreateButton) -> &'b mut CreateButton {
+    fn create_done_button(button: &mut CreateButton) -> &mut CreateButton {
         button
             .style(ButtonStyle::Success)
             .label("Complete")
             .custom_id("done")
     }
 
-    fn create_scout_link<'b>(url: &String, button: &'b mut CreateButton) -> &'b mut CreateButton {
+    fn create_scout_link<'b>(
+        url: &String,
+        locale: Locale,
+        button: &'b mut CreateButton,
+    ) -> &'b mut CreateButton {
         button
             .style(ButtonStyle::Link)
-            .label("Scouted Map")
+            .label(locale.text(Text::ScoutedMap))
             .url(url)
     }
 }
@@ -170,6 +298,14 @@ pub enum Status {
     Waiting = 1,
     Scouted = 2,
     Running = 3,
+    /// Waiting on the marks that spawn after server maintenance.
+    Maintenance = 4,
+    /// Marks killed outside of a train.
+    Sniped = 5,
+    /// Run until some marks were killed, with the rest still alive.
+    Partial = 6,
+    /// Given up before any marks were killed.
+    Abandoned = 7,
 }
 
 impl Status {
@@ -180,10 +316,48 @@ impl Status {
             Waiting => "🕑",
             Scouted => "☑️",
             Running => "➡️",
+            Maintenance => "🔧",
+            Sniped => "🎯",
+            Partial => "🌓",
+            Abandoned => "🚫",
         }
     }
 }
 
+#[poise::async_trait]
+impl SlashArgument for Status {
+    async fn extract(
+        _ctx: &serenity::Context,
+        _interaction: poise::ApplicationCommandOrAutocompleteInteraction<'_>,
+        value: &poise::serenity_prelude::json::Value,
+    ) -> Result<Self, poise::SlashArgError> {
+        let choice = value
+            .as_u64()
+            .ok_or(poise::SlashArgError::CommandStructureMismatch(
+                "expected u64",
+            ))?;
+        Self::iter()
+            // TODO: technically this can overflow on 32-bit systems
+            .nth(choice as usize)
+            .ok_or(poise::SlashArgError::CommandStructureMismatch(
+                "argument out of range",
+            ))
+    }
+
+    fn create(builder: &mut serenity::CreateApplicationCommandOption) {
+        builder.kind(poise::serenity_prelude::CommandOptionType::Integer);
+    }
+
+    fn choices() -> Vec<poise::CommandParameterChoice> {
+        Self::iter()
+            .map(|status| poise::CommandParameterChoice {
+                name: status.to_string(),
+                localizations: choice_localizations(|locale| locale.status(status).to_owned()),
+            })
+            .collect()
+    }
+}
+
 #[derive(Debug, Iden)]
 pub struct StatusEnum;
 
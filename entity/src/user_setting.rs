@@ -0,0 +1,19 @@
+use sea_orm::entity::prelude::*;
+
+/// Preferences of a single Discord user.
+#[derive(Clone, Debug, DeriveEntityModel)]
+#[sea_orm(table_name = "user_settings")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i64,
+    #[sea_orm(unique)]
+    pub user_id: i64,
+    /// Zone that wall-clock times entered by the user are in, as an
+    /// abbreviation or a UTC offset. Server Time (UTC) if unset.
+    pub timezone: Option<String>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -2,6 +2,25 @@ pub use sea_orm_migration::prelude::*;
 
 mod m20221224_000001_initial;
 mod m20221224_232202_dashboard;
+mod m20261018_000001_content_hash;
+mod m20261018_000002_sticky;
+mod m20261018_000003_dashboard_settings;
+mod m20261018_000004_data_center;
+mod m20261018_000005_themes;
+mod m20261018_000006_templates;
+mod m20261018_000007_guild_settings;
+mod m20261018_000008_user_settings;
+mod m20261018_000009_train_updated_at;
+mod m20261018_000010_train_maintenance;
+mod m20261018_000011_maintenance_windows;
+mod m20261018_000012_scheduled_trains;
+mod m20261018_000013_scheduled_train_event;
+mod m20261018_000014_recurring_trains;
+mod m20261018_000015_force_alerts;
+mod m20261018_000016_expiry_rules;
+mod m20261018_000017_train_marks_left;
+mod m20261018_000018_mark_kills;
+mod m20261018_000019_s_ranks;
 
 pub struct Migrator;
 
@@ -11,6 +30,25 @@ impl MigratorTrait for Migrator {
         vec![
             Box::new(m20221224_000001_initial::Migration),
             Box::new(m20221224_232202_dashboard::Migration),
+            Box::new(m20261018_000001_content_hash::Migration),
+            Box::new(m20261018_000002_sticky::Migration),
+            Box::new(m20261018_000003_dashboard_settings::Migration),
+            Box::new(m20261018_000004_data_center::Migration),
+            Box::new(m20261018_000005_themes::Migration),
+            Box::new(m20261018_000006_templates::Migration),
+            Box::new(m20261018_000007_guild_settings::Migration),
+            Box::new(m20261018_000008_user_settings::Migration),
+            Box::new(m20261018_000009_train_updated_at::Migration),
+            Box::new(m20261018_000010_train_maintenance::Migration),
+            Box::new(m20261018_000011_maintenance_windows::Migration),
+            Box::new(m20261018_000012_scheduled_trains::Migration),
+            Box::new(m20261018_000013_scheduled_train_event::Migration),
+            Box::new(m20261018_000014_recurring_trains::Migration),
+            Box::new(m20261018_000015_force_alerts::Migration),
+            Box::new(m20261018_000016_expiry_rules::Migration),
+            Box::new(m20261018_000017_train_marks_left::Migration),
+            Box::new(m20261018_000018_mark_kills::Migration),
+            Box::new(m20261018_000019_s_ranks::Migration),
         ]
     }
 }
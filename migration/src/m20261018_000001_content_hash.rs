@@ -0,0 +1,49 @@
+use sea_orm_migration::prelude::*;
+
+use entity::dashboard::Column as Dashboards;
+use entity::monitor::Column as Monitors;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // SQLite only supports one column per ALTER TABLE.
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(entity::Table::Monitors)
+                    .add_column(ColumnDef::new(Monitors::ContentHash).big_integer())
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(entity::Table::Dashboards)
+                    .add_column(ColumnDef::new(Dashboards::ContentHash).big_integer())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(entity::Table::Monitors)
+                    .drop_column(Monitors::ContentHash)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(entity::Table::Dashboards)
+                    .drop_column(Dashboards::ContentHash)
+                    .to_owned(),
+            )
+            .await
+    }
+}
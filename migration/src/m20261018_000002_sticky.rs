@@ -0,0 +1,58 @@
+use sea_orm_migration::prelude::*;
+
+use entity::dashboard::Column as Dashboards;
+use entity::monitor::Column as Monitors;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(entity::Table::Monitors)
+                    .add_column(
+                        ColumnDef::new(Monitors::Sticky)
+                            .boolean()
+                            .not_null()
+                            .default(false),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(entity::Table::Dashboards)
+                    .add_column(
+                        ColumnDef::new(Dashboards::Sticky)
+                            .boolean()
+                            .not_null()
+                            .default(false),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(entity::Table::Monitors)
+                    .drop_column(Monitors::Sticky)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(entity::Table::Dashboards)
+                    .drop_column(Dashboards::Sticky)
+                    .to_owned(),
+            )
+            .await
+    }
+}
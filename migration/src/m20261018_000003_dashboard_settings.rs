@@ -0,0 +1,64 @@
+use sea_orm_migration::prelude::*;
+
+use entity::dashboard::Column as Dashboards;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let columns = [
+            ColumnDef::new(Dashboards::Worlds).text().to_owned(),
+            ColumnDef::new(Dashboards::Expacs).text().to_owned(),
+            ColumnDef::new(Dashboards::Transposed)
+                .boolean()
+                .not_null()
+                .default(false)
+                .to_owned(),
+            ColumnDef::new(Dashboards::SortByForce)
+                .boolean()
+                .not_null()
+                .default(false)
+                .to_owned(),
+            ColumnDef::new(Dashboards::Compact)
+                .boolean()
+                .not_null()
+                .default(false)
+                .to_owned(),
+        ];
+        // SQLite only supports one column per ALTER TABLE.
+        for mut column in columns {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(entity::Table::Dashboards)
+                        .add_column(&mut column)
+                        .to_owned(),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let columns = [
+            Dashboards::Worlds,
+            Dashboards::Expacs,
+            Dashboards::Transposed,
+            Dashboards::SortByForce,
+            Dashboards::Compact,
+        ];
+        for column in columns {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(entity::Table::Dashboards)
+                        .drop_column(column)
+                        .to_owned(),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+}
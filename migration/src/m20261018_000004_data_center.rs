@@ -0,0 +1,52 @@
+use sea_orm_migration::prelude::*;
+
+use entity::dashboard::Column as Dashboards;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(entity::Table::Dashboards)
+                    .add_column(ColumnDef::new(Dashboards::DataCenter).text())
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(entity::Table::Dashboards)
+                    .add_column(
+                        ColumnDef::new(Dashboards::Overview)
+                            .boolean()
+                            .not_null()
+                            .default(false),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(entity::Table::Dashboards)
+                    .drop_column(Dashboards::DataCenter)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(entity::Table::Dashboards)
+                    .drop_column(Dashboards::Overview)
+                    .to_owned(),
+            )
+            .await
+    }
+}
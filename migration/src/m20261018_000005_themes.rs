@@ -0,0 +1,36 @@
+use sea_orm_migration::prelude::*;
+
+use entity::theme::Column as Themes;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(entity::Table::Themes)
+                    .col(ColumnDef::new(Themes::Id).integer().primary_key())
+                    .col(ColumnDef::new(Themes::GuildId).integer().not_null())
+                    .col(ColumnDef::new(Themes::Status).integer().not_null())
+                    .col(ColumnDef::new(Themes::Emoji).text())
+                    .col(ColumnDef::new(Themes::Colour).integer())
+                    .index(
+                        Index::create()
+                            .unique()
+                            .col(Themes::GuildId)
+                            .col(Themes::Status),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(entity::Table::Themes).to_owned())
+            .await
+    }
+}
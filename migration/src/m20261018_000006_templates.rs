@@ -0,0 +1,52 @@
+use sea_orm_migration::prelude::*;
+
+use entity::template::Column as Templates;
+use entity::train::Column as Trains;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(entity::Table::Templates)
+                    .col(ColumnDef::new(Templates::Id).integer().primary_key())
+                    .col(ColumnDef::new(Templates::GuildId).integer().not_null())
+                    .col(ColumnDef::new(Templates::Key).text().not_null())
+                    .col(ColumnDef::new(Templates::Text).text().not_null())
+                    .index(
+                        Index::create()
+                            .unique()
+                            .col(Templates::GuildId)
+                            .col(Templates::Key),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(entity::Table::Trains)
+                    .add_column(ColumnDef::new(Trains::Conductor).integer())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(entity::Table::Trains)
+                    .drop_column(Trains::Conductor)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_table(Table::drop().table(entity::Table::Templates).to_owned())
+            .await
+    }
+}
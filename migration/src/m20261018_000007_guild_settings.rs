@@ -0,0 +1,33 @@
+use sea_orm_migration::prelude::*;
+
+use entity::guild_setting::Column as GuildSettings;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(entity::Table::GuildSettings)
+                    .col(ColumnDef::new(GuildSettings::Id).integer().primary_key())
+                    .col(
+                        ColumnDef::new(GuildSettings::GuildId)
+                            .integer()
+                            .not_null()
+                            .unique_key(),
+                    )
+                    .col(ColumnDef::new(GuildSettings::Locale).text().not_null())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(entity::Table::GuildSettings).to_owned())
+            .await
+    }
+}
@@ -0,0 +1,33 @@
+use sea_orm_migration::prelude::*;
+
+use entity::user_setting::Column as UserSettings;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(entity::Table::UserSettings)
+                    .col(ColumnDef::new(UserSettings::Id).integer().primary_key())
+                    .col(
+                        ColumnDef::new(UserSettings::UserId)
+                            .integer()
+                            .not_null()
+                            .unique_key(),
+                    )
+                    .col(ColumnDef::new(UserSettings::Timezone).text())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(entity::Table::UserSettings).to_owned())
+            .await
+    }
+}
@@ -0,0 +1,31 @@
+use sea_orm_migration::prelude::*;
+
+use entity::train::Column as Trains;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(entity::Table::Trains)
+                    .add_column(ColumnDef::new(Trains::MaintenanceEnd).timestamp_with_time_zone())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(entity::Table::Trains)
+                    .drop_column(Trains::MaintenanceEnd)
+                    .to_owned(),
+            )
+            .await
+    }
+}
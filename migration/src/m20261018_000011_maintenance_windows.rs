@@ -0,0 +1,78 @@
+use sea_orm_migration::prelude::*;
+
+use entity::maintenance_window::Column as MaintenanceWindows;
+use entity::notice_channel::Column as NoticeChannels;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(entity::Table::MaintenanceWindows)
+                    .col(
+                        ColumnDef::new(MaintenanceWindows::Id)
+                            .integer()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(MaintenanceWindows::StartTime)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(MaintenanceWindows::EndTime)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(MaintenanceWindows::Started)
+                            .boolean()
+                            .not_null()
+                            .default(false),
+                    )
+                    .col(
+                        ColumnDef::new(MaintenanceWindows::Ended)
+                            .boolean()
+                            .not_null()
+                            .default(false),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_table(
+                Table::create()
+                    .table(entity::Table::NoticeChannels)
+                    .col(ColumnDef::new(NoticeChannels::Id).integer().primary_key())
+                    .col(
+                        ColumnDef::new(NoticeChannels::ChannelId)
+                            .integer()
+                            .not_null()
+                            .unique_key(),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(entity::Table::NoticeChannels)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(entity::Table::MaintenanceWindows)
+                    .to_owned(),
+            )
+            .await
+    }
+}
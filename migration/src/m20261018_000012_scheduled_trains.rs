@@ -0,0 +1,63 @@
+use sea_orm_migration::prelude::*;
+
+use entity::scheduled_train::Column as ScheduledTrains;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(entity::Table::ScheduledTrains)
+                    .col(ColumnDef::new(ScheduledTrains::Id).integer().primary_key())
+                    .col(
+                        ColumnDef::new(ScheduledTrains::TrainId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(ScheduledTrains::StartTime)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(ScheduledTrains::Conductor)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(ScheduledTrains::Notes).text())
+                    .col(
+                        ColumnDef::new(ScheduledTrains::RemindMinutes)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(ScheduledTrains::ChannelId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(ScheduledTrains::Reminded)
+                            .boolean()
+                            .not_null()
+                            .default(false),
+                    )
+                    .col(ColumnDef::new(ScheduledTrains::StartedAt).timestamp_with_time_zone())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(entity::Table::ScheduledTrains)
+                    .to_owned(),
+            )
+            .await
+    }
+}
@@ -0,0 +1,31 @@
+use sea_orm_migration::prelude::*;
+
+use entity::scheduled_train::Column as ScheduledTrains;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(entity::Table::ScheduledTrains)
+                    .add_column(ColumnDef::new(ScheduledTrains::EventId).integer())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(entity::Table::ScheduledTrains)
+                    .drop_column(ScheduledTrains::EventId)
+                    .to_owned(),
+            )
+            .await
+    }
+}
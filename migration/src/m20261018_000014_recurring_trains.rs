@@ -0,0 +1,86 @@
+use sea_orm_migration::prelude::*;
+
+use entity::recurring_train::Column as RecurringTrains;
+use entity::scheduled_train::Column as ScheduledTrains;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(entity::Table::RecurringTrains)
+                    .col(ColumnDef::new(RecurringTrains::Id).integer().primary_key())
+                    .col(
+                        ColumnDef::new(RecurringTrains::TrainId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(RecurringTrains::StartTime)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(RecurringTrains::IntervalWeeks)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(RecurringTrains::Conductor)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(RecurringTrains::Notes).text())
+                    .col(
+                        ColumnDef::new(RecurringTrains::RemindMinutes)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(RecurringTrains::ChannelId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(RecurringTrains::Location).text())
+                    .col(
+                        ColumnDef::new(RecurringTrains::Paused)
+                            .boolean()
+                            .not_null()
+                            .default(false),
+                    )
+                    .col(ColumnDef::new(RecurringTrains::PlannedUntil).timestamp_with_time_zone())
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(entity::Table::ScheduledTrains)
+                    .add_column(ColumnDef::new(ScheduledTrains::SeriesId).integer())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(entity::Table::ScheduledTrains)
+                    .drop_column(ScheduledTrains::SeriesId)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(entity::Table::RecurringTrains)
+                    .to_owned(),
+            )
+            .await
+    }
+}
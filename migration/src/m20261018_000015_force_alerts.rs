@@ -0,0 +1,36 @@
+use sea_orm_migration::prelude::*;
+
+use entity::force_alert::Column as ForceAlerts;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(entity::Table::ForceAlerts)
+                    .col(ColumnDef::new(ForceAlerts::Id).integer().primary_key())
+                    .col(ColumnDef::new(ForceAlerts::ChannelId).integer().not_null())
+                    .col(ColumnDef::new(ForceAlerts::Kind).text().not_null())
+                    .col(ColumnDef::new(ForceAlerts::RoleId).integer())
+                    .col(ColumnDef::new(ForceAlerts::Minutes).integer().not_null())
+                    .index(
+                        Index::create()
+                            .unique()
+                            .col(ForceAlerts::ChannelId)
+                            .col(ForceAlerts::Kind),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(entity::Table::ForceAlerts).to_owned())
+            .await
+    }
+}
@@ -0,0 +1,35 @@
+use sea_orm_migration::prelude::*;
+
+use entity::expiry_rule::Column as ExpiryRules;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(entity::Table::ExpiryRules)
+                    .col(ColumnDef::new(ExpiryRules::Id).integer().primary_key())
+                    .col(ColumnDef::new(ExpiryRules::GuildId).integer().not_null())
+                    .col(ColumnDef::new(ExpiryRules::Status).integer().not_null())
+                    .col(ColumnDef::new(ExpiryRules::Hours).integer().not_null())
+                    .index(
+                        Index::create()
+                            .unique()
+                            .col(ExpiryRules::GuildId)
+                            .col(ExpiryRules::Status),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(entity::Table::ExpiryRules).to_owned())
+            .await
+    }
+}
@@ -0,0 +1,39 @@
+use sea_orm_migration::prelude::*;
+
+use entity::mark_kill::Column as MarkKills;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(entity::Table::MarkKills)
+                    .col(ColumnDef::new(MarkKills::Id).integer().primary_key())
+                    .col(ColumnDef::new(MarkKills::World).text().not_null())
+                    .col(ColumnDef::new(MarkKills::Mark).text().not_null())
+                    .col(
+                        ColumnDef::new(MarkKills::KilledAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .index(
+                        Index::create()
+                            .unique()
+                            .col(MarkKills::World)
+                            .col(MarkKills::Mark),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(entity::Table::MarkKills).to_owned())
+            .await
+    }
+}
@@ -0,0 +1,73 @@
+use sea_orm_migration::prelude::*;
+
+use entity::s_rank::Column as SRanks;
+use entity::s_rank_dashboard::Column as SRankDashboards;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(entity::Table::SRanks)
+                    .col(ColumnDef::new(SRanks::Id).integer().primary_key())
+                    .col(ColumnDef::new(SRanks::World).text().not_null())
+                    .col(ColumnDef::new(SRanks::Zone).text().not_null())
+                    .col(ColumnDef::new(SRanks::LastKill).timestamp_with_time_zone())
+                    .col(ColumnDef::new(SRanks::MinHours).integer().not_null())
+                    .col(ColumnDef::new(SRanks::MaxHours).integer().not_null())
+                    .col(ColumnDef::new(SRanks::Notes).text())
+                    .index(
+                        Index::create()
+                            .unique()
+                            .col(SRanks::World)
+                            .col(SRanks::Zone),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_table(
+                Table::create()
+                    .table(entity::Table::SRankDashboards)
+                    .col(ColumnDef::new(SRankDashboards::Id).integer().primary_key())
+                    .col(
+                        ColumnDef::new(SRankDashboards::MessageId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(SRankDashboards::ChannelId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(SRankDashboards::ContentHash).big_integer())
+                    .col(
+                        ColumnDef::new(SRankDashboards::Sticky)
+                            .boolean()
+                            .not_null()
+                            .default(false),
+                    )
+                    .col(ColumnDef::new(SRankDashboards::Worlds).text())
+                    .col(ColumnDef::new(SRankDashboards::Expacs).text())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(entity::Table::SRankDashboards)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_table(Table::drop().table(entity::Table::SRanks).to_owned())
+            .await
+    }
+}
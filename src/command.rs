@@ -1,5 +1,11 @@
+pub mod admin;
 pub mod argument;
+pub mod autocomplete;
+pub mod hunt;
+mod locale;
+pub mod maintenance;
 
+use entity::locale::Locale;
 use poise::serenity_prelude as serenity;
 
 pub type Context<'a> = poise::Context<'a, Data, eyre::Report>;
@@ -7,6 +13,17 @@ pub type Context<'a> = poise::Context<'a, Data, eyre::Report>;
 pub struct Data {
     pub db: sea_orm::DbConn,
     pub train_guild_id: serenity::GuildId,
+    pub refresh: crate::refresh::Queue,
+}
+
+/// The language to reply in: the invoking user's if we have translations for
+/// it, otherwise the one picked for the train guild.
+pub async fn reply_locale(ctx: Context<'_>) -> eyre::Result<Locale> {
+    if let Some(locale) = ctx.locale().and_then(Locale::from_code) {
+        return Ok(locale);
+    }
+    let guild_id = ctx.data().train_guild_id.0 as i64;
+    Ok(Locale::for_guild(&ctx.data().db, guild_id).await?)
 }
 
 #[poise::command(prefix_command, owners_only)]
@@ -26,5 +43,15 @@ pub async fn hello(ctx: Context<'_>) -> eyre::Result<()> {
 }
 
 pub fn all() -> Vec<poise::Command<Data, eyre::Report>> {
-    vec![crate::train::command::train(), hello(), delete_message()]
+    let mut commands = vec![
+        crate::train::command::train(),
+        maintenance::maintenance(),
+        hunt::hunt(),
+        crate::srank::command::srank(),
+        admin::admin(),
+        hello(),
+        delete_message(),
+    ];
+    locale::localize(&mut commands);
+    commands
 }
@@ -0,0 +1,21 @@
+use eyre::eyre;
+
+use super::Context;
+use crate::reconcile;
+
+/// Bot administration commands.
+#[poise::command(slash_command, owners_only, subcommands("reconcile"))]
+pub async fn admin(_ctx: Context<'_>) -> eyre::Result<()> {
+    Err(eyre!("unsupported"))
+}
+
+/// Check every monitor and dashboard post, pruning or reposting the ones that are gone
+#[poise::command(slash_command, owners_only)]
+pub async fn reconcile(ctx: Context<'_>) -> eyre::Result<()> {
+    ctx.defer_ephemeral().await?;
+    let data = ctx.data();
+    let summary = reconcile::reconcile(&ctx.discord().http, &data.db, &data.refresh).await?;
+    ctx.say(format!("Reconciliation complete: {}.", summary))
+        .await?;
+    Ok(())
+}
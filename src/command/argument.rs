@@ -1,9 +1,484 @@
-use chrono::{DateTime, TimeZone, Utc};
+use chrono::{DateTime, Duration, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
 use eyre::eyre;
 use poise::serenity_prelude as serenity;
 use poise::SlashArgument;
+use sea_orm::{ColumnTrait, EntityTrait, QueryFilter};
 
-pub struct Timestamp(pub DateTime<Utc>);
+use super::Context;
+use entity::{user_setting, Expac, World};
+
+const EXAMPLES: &str = "Try \"10m ago\", \"in 2h\", \"21:30\", \"9:30pm EST\", \
+                        \"2026-10-18T21:30Z\" or a Discord timestamp.";
+
+/// How far from now a time may be. Nothing the bot tracks is further away, and
+/// it keeps later arithmetic on stored times well within chrono's range.
+const MAX_DAYS_AWAY: i64 = 366;
+
+/// A point in time entered by a user.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Timestamp {
+    /// A time that means the same thing whoever entered it.
+    Exact(DateTime<Utc>),
+    /// A wall-clock time, in `zone` if one was given and otherwise in the zone
+    /// of whoever entered it. Without a date, which occurrence is meant
+    /// depends on how the time is resolved.
+    Local {
+        date: Option<NaiveDate>,
+        time: NaiveTime,
+        zone: Option<FixedOffset>,
+    },
+}
+
+impl Timestamp {
+    /// Accepts Discord timestamps (`<t:1666000000:f>`), Unix timestamps,
+    /// relative times ("10m ago", "in 1h 30m"), wall-clock times with an
+    /// optional zone ("21:30", "9:30pm EST", "21:30 UTC+2") and ISO 8601.
+    pub fn parse(input: &str, now: DateTime<Utc>) -> Result<Self, String> {
+        let timestamp = Self::parse_any(input, now)?;
+        let days_away = match timestamp {
+            Timestamp::Exact(t) => t.signed_duration_since(now).num_days(),
+            Timestamp::Local {
+                date: Some(date), ..
+            } => date.signed_duration_since(now.date_naive()).num_days(),
+            Timestamp::Local { date: None, .. } => 0,
+        };
+        if days_away.abs() > MAX_DAYS_AWAY {
+            return Err(format!(
+                "\"{}\" is too far from now; give a time within a year.",
+                input.trim()
+            ));
+        }
+        Ok(timestamp)
+    }
+
+    fn parse_any(input: &str, now: DateTime<Utc>) -> Result<Self, String> {
+        let trimmed = input.trim();
+        let lower = trimmed.to_lowercase();
+        if lower.is_empty() {
+            return Err(format!("No time given. {}", EXAMPLES));
+        }
+        if lower == "now" {
+            return Ok(Timestamp::Exact(now));
+        }
+        // We accept discord-formatted timestamps, or more accurately, anything between two colons.
+        if let Some(tag) = lower.strip_prefix("<t:") {
+            let seconds = tag.split([':', '>']).next().unwrap_or_default();
+            return unix(seconds).map(Timestamp::Exact);
+        }
+        if lower.bytes().all(|b| b.is_ascii_digit()) {
+            return unix(&lower).map(Timestamp::Exact);
+        }
+        if let Some(duration) = lower.strip_prefix("in ") {
+            return now
+                .checked_add_signed(parse_duration(duration)?)
+                .map(Timestamp::Exact)
+                .ok_or_else(|| "Time out of range.".to_owned());
+        }
+        if let Some(duration) = lower.strip_suffix(" ago").or(lower.strip_suffix("ago")) {
+            return now
+                .checked_sub_signed(parse_duration(duration)?)
+                .map(Timestamp::Exact)
+                .ok_or_else(|| "Time out of range.".to_owned());
+        }
+        if parse_duration(&lower).is_ok() {
+            return Err(format!(
+                "\"{0}\" could be in the past or the future; write \"{0} ago\" or \"in {0}\".",
+                trimmed
+            ));
+        }
+        if let Ok(t) = DateTime::parse_from_rfc3339(trimmed) {
+            return Ok(Timestamp::Exact(t.with_timezone(&Utc)));
+        }
+        for format in ["%Y-%m-%dT%H:%M%#z", "%Y-%m-%d %H:%M%#z"] {
+            if let Ok(t) = DateTime::parse_from_str(trimmed, format) {
+                return Ok(Timestamp::Exact(t.with_timezone(&Utc)));
+            }
+        }
+
+        // Anything else is a local date and/or time, possibly followed by a zone.
+        let (local, zone) = match trimmed.rsplit_once(' ') {
+            Some((local, zone)) => match parse_zone(zone) {
+                Some(zone) => (local.trim(), Some(zone)),
+                None => (trimmed, None),
+            },
+            None => (trimmed, None),
+        };
+        let Some((date, time)) = parse_local(local) else {
+            return Err(match trimmed.rsplit_once(' ') {
+                Some((local, zone)) if parse_local(local).is_some() => format!(
+                    "Unknown time zone \"{}\". Use an abbreviation such as EST or CET, \
+                     or an offset such as UTC+2.",
+                    zone
+                ),
+                _ => format!("Couldn't understand \"{}\". {}", trimmed, EXAMPLES),
+            });
+        };
+        Ok(Timestamp::Local { date, time, zone })
+    }
+
+    /// The time in UTC, taking wall-clock times without a zone to be in
+    /// `zone`. Without a date, the occurrence closest to `now` is meant, which
+    /// suits times that are usually in the past, such as kill times.
+    /// None if the time is out of chrono's range.
+    pub fn resolve_in(&self, zone: FixedOffset, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        match *self {
+            Timestamp::Local {
+                date: None,
+                time,
+                zone: given,
+            } => days_around(given.unwrap_or(zone), time, now)
+                .min_by_key(|t| t.signed_duration_since(now).num_seconds().abs()),
+            _ => self.next_in(zone, now),
+        }
+    }
+
+    /// Like `resolve_in`, but without a date the first occurrence at or after
+    /// `after` is meant, which suits times that are planned ahead.
+    pub fn next_in(&self, zone: FixedOffset, after: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        match *self {
+            Timestamp::Exact(t) => Some(t),
+            Timestamp::Local {
+                date: Some(date),
+                time,
+                zone: given,
+            } => local_to_utc(given.unwrap_or(zone), date.and_time(time)),
+            Timestamp::Local {
+                date: None,
+                time,
+                zone: given,
+            } => days_around(given.unwrap_or(zone), time, after).find(|t| *t >= after),
+        }
+    }
+
+    /// The time in UTC, taking wall-clock times to be in the invoking user's
+    /// saved time zone, or Server Time (UTC) if they have not saved one.
+    pub async fn resolve(&self, ctx: Context<'_>) -> eyre::Result<DateTime<Utc>> {
+        self.resolve_in(user_zone(ctx).await?, Utc::now())
+            .ok_or_else(|| eyre!("That time is out of range"))
+    }
+
+    /// Like `resolve`, but for times planned at or after `after`.
+    pub async fn resolve_after(
+        &self,
+        ctx: Context<'_>,
+        after: DateTime<Utc>,
+    ) -> eyre::Result<DateTime<Utc>> {
+        self.next_in(user_zone(ctx).await?, after)
+            .ok_or_else(|| eyre!("That time is out of range"))
+    }
+}
+
+/// The wall-clock `time` in `zone` on the day before, of and after `around`,
+/// in order.
+fn days_around(
+    zone: FixedOffset,
+    time: NaiveTime,
+    around: DateTime<Utc>,
+) -> impl Iterator<Item = DateTime<Utc>> {
+    let today = around.with_timezone(&zone).date_naive();
+    [today.pred_opt(), Some(today), today.succ_opt()]
+        .into_iter()
+        .flatten()
+        .filter_map(move |date| local_to_utc(zone, date.and_time(time)))
+}
+
+/// None if the time is out of chrono's range, where `from_local_datetime`
+/// would panic.
+fn local_to_utc(zone: FixedOffset, local: NaiveDateTime) -> Option<DateTime<Utc>> {
+    local
+        .checked_sub_signed(Duration::seconds(zone.local_minus_utc().into()))
+        .map(|utc| Utc.from_utc_datetime(&utc))
+}
+
+fn unix(seconds: &str) -> Result<DateTime<Utc>, String> {
+    let seconds = seconds
+        .parse()
+        .map_err(|_| format!("\"{}\" is not a Unix timestamp.", seconds))?;
+    match Utc.timestamp_opt(seconds, 0) {
+        chrono::LocalResult::Single(t) => Ok(t),
+        _ => Err("Timestamp out of range.".to_owned()),
+    }
+}
+
+/// Parses durations such as "10m", "2 hours" or "1h 30m".
+fn parse_duration(input: &str) -> Result<Duration, String> {
+    // Keeps the total well within what `Duration` can represent.
+    const MAX_AMOUNT: i64 = 10_000;
+
+    let mut rest = input.trim();
+    if rest.is_empty() {
+        return Err(format!("No duration given. {}", EXAMPLES));
+    }
+    let mut total = Duration::zero();
+    while !rest.is_empty() {
+        let digits = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        if digits == 0 {
+            return Err(format!("Expected a number at \"{}\". {}", rest, EXAMPLES));
+        }
+        let amount: i64 = rest[..digits]
+            .parse()
+            .ok()
+            .filter(|&amount| amount <= MAX_AMOUNT)
+            .ok_or_else(|| format!("{} is too large.", &rest[..digits]))?;
+        rest = rest[digits..].trim_start();
+        let letters = rest
+            .find(|c: char| !c.is_alphabetic())
+            .unwrap_or(rest.len());
+        let duration = match &rest[..letters] {
+            "s" | "sec" | "secs" | "second" | "seconds" => Duration::seconds(amount),
+            "m" | "min" | "mins" | "minute" | "minutes" => Duration::minutes(amount),
+            "h" | "hr" | "hrs" | "hour" | "hours" => Duration::hours(amount),
+            "d" | "day" | "days" => Duration::days(amount),
+            "" => return Err(format!("Missing a unit after {}, e.g. \"{0}m\".", amount)),
+            unit => return Err(format!("Unknown unit \"{}\"; use s, m, h or d.", unit)),
+        };
+        total = total + duration;
+        rest = rest[letters..].trim_start_matches([' ', ',']);
+    }
+    Ok(total)
+}
+
+/// Parses a wall-clock time, optionally preceded by an ISO 8601 date.
+fn parse_local(input: &str) -> Option<(Option<NaiveDate>, NaiveTime)> {
+    for format in [
+        "%Y-%m-%dT%H:%M:%S",
+        "%Y-%m-%dT%H:%M",
+        "%Y-%m-%d %H:%M:%S",
+        "%Y-%m-%d %H:%M",
+    ] {
+        if let Ok(t) = NaiveDateTime::parse_from_str(input, format) {
+            return Some((Some(t.date()), t.time()));
+        }
+    }
+    parse_clock(input).map(|time| (None, time))
+}
+
+/// Parses times of day such as "21:30", "21:30:15", "9:30pm" or "9 pm".
+fn parse_clock(input: &str) -> Option<NaiveTime> {
+    let input = input.trim().to_lowercase();
+    let (clock, pm) = if let Some(clock) = input.strip_suffix("am") {
+        (clock.trim_end(), Some(false))
+    } else if let Some(clock) = input.strip_suffix("pm") {
+        (clock.trim_end(), Some(true))
+    } else {
+        (input.as_str(), None)
+    };
+    let mut parts = clock.split(':');
+    let hour: u32 = parts.next()?.parse().ok()?;
+    let minute: u32 = match parts.next() {
+        Some(minute) => minute.parse().ok()?,
+        // A bare number is only a time with am/pm.
+        None if pm.is_some() => 0,
+        None => return None,
+    };
+    let second: u32 = match parts.next() {
+        Some(second) => second.parse().ok()?,
+        None => 0,
+    };
+    if parts.next().is_some() {
+        return None;
+    }
+    let hour = match pm {
+        Some(_) if !(1..=12).contains(&hour) => return None,
+        Some(pm) => hour % 12 + if pm { 12 } else { 0 },
+        None => hour,
+    };
+    NaiveTime::from_hms_opt(hour, minute, second)
+}
+
+/// Common abbreviations and their offsets from UTC in minutes. Abbreviations
+/// name either standard or daylight saving time, so they are unambiguous.
+const ZONES: &[(&str, i32)] = &[
+    ("UTC", 0),
+    ("GMT", 0),
+    // FFXIV's Server Time.
+    ("ST", 0),
+    ("WET", 0),
+    ("WEST", 60),
+    ("BST", 60),
+    ("CET", 60),
+    ("CEST", 120),
+    ("EET", 120),
+    ("EEST", 180),
+    ("HST", -600),
+    ("AKST", -540),
+    ("AKDT", -480),
+    ("PST", -480),
+    ("PDT", -420),
+    ("MST", -420),
+    ("MDT", -360),
+    ("CST", -360),
+    ("CDT", -300),
+    ("EST", -300),
+    ("EDT", -240),
+    ("SGT", 480),
+    ("HKT", 480),
+    ("AWST", 480),
+    ("JST", 540),
+    ("KST", 540),
+    ("ACST", 570),
+    ("AEST", 600),
+    ("AEDT", 660),
+    ("NZST", 720),
+    ("NZDT", 780),
+];
+
+/// Parses a zone abbreviation or an offset such as "UTC+2", "GMT-05:30" or "+0900".
+pub fn parse_zone(input: &str) -> Option<FixedOffset> {
+    let input = input.trim().to_uppercase();
+    if let Some(&(_, minutes)) = ZONES.iter().find(|(name, _)| *name == input) {
+        return FixedOffset::east_opt(minutes * 60);
+    }
+    let offset = input
+        .strip_prefix("UTC")
+        .or_else(|| input.strip_prefix("GMT"))
+        .unwrap_or(&input);
+    let (sign, offset) = if let Some(rest) = offset.strip_prefix('+') {
+        (1, rest)
+    } else if let Some(rest) = offset.strip_prefix('-') {
+        (-1, rest)
+    } else {
+        return None;
+    };
+    let (hours, minutes) = match offset.split_once(':') {
+        Some((hours, minutes)) => (hours, minutes),
+        // "+0930": the last two digits are minutes.
+        None => match offset.char_indices().rev().nth(1) {
+            Some((i, _)) if i > 0 => offset.split_at(i),
+            _ => (offset, "0"),
+        },
+    };
+    if !(hours.bytes().all(|b| b.is_ascii_digit()) && minutes.bytes().all(|b| b.is_ascii_digit())) {
+        return None;
+    }
+    let hours: i32 = hours.parse().ok()?;
+    let minutes: i32 = minutes.parse().ok()?;
+    if hours > 14 || minutes >= 60 {
+        return None;
+    }
+    FixedOffset::east_opt(sign * (hours * 3600 + minutes * 60))
+}
+
+/// The invoking user's saved time zone, or Server Time (UTC).
+pub async fn user_zone(ctx: Context<'_>) -> eyre::Result<FixedOffset> {
+    let saved = user_setting::Entity::find()
+        .filter(user_setting::Column::UserId.eq(ctx.author().id.0 as i64))
+        .one(&ctx.data().db)
+        .await?;
+    let zone = saved
+        .and_then(|s| s.timezone)
+        .unwrap_or_else(|| "UTC".to_owned());
+    parse_zone(&zone).ok_or_else(|| eyre!("Saved time zone \"{}\" is not valid", zone))
+}
+
+/// A train named by its world and expansion in a single argument, in either
+/// order: "Seraph EW", "EW Seraph" or "seraph/endwalker".
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct TrainRef {
+    pub world: World,
+    pub expac: Expac,
+}
+
+impl TrainRef {
+    pub fn parse(input: &str) -> Result<Self, String> {
+        let words: Vec<_> = input
+            .split(|c: char| c.is_whitespace() || c == '/' || c == ',')
+            .filter(|w| !w.is_empty())
+            .collect();
+        // Expansion names can contain spaces, so try every way to split the words.
+        let mut found = Vec::new();
+        let mut ambiguous = None;
+        for split in 1..words.len() {
+            let (first, second) = (words[..split].join(" "), words[split..].join(" "));
+            let candidates = [
+                World::lookup(&first).and_then(|w| Ok((w, Expac::lookup(&second)?))),
+                Expac::lookup(&first).and_then(|e| Ok((World::lookup(&second)?, e))),
+            ];
+            for candidate in candidates {
+                match candidate {
+                    Ok((world, expac)) => {
+                        let train = TrainRef { world, expac };
+                        if !found.contains(&train) {
+                            found.push(train);
+                        }
+                    }
+                    Err(e) if e.ambiguous => ambiguous = ambiguous.or(Some(e)),
+                    Err(_) => {}
+                }
+            }
+        }
+        match found[..] {
+            [train] => Ok(train),
+            [] => Err(match ambiguous {
+                Some(e) => e.to_string(),
+                None => format!(
+                    "Couldn't find a world and an expansion in \"{}\". Try e.g. \"Seraph EW\".",
+                    input.trim()
+                ),
+            }),
+            _ => {
+                let names: Vec<_> = found.iter().map(|t| t.to_string()).collect();
+                Err(format!(
+                    "\"{}\" could be {}",
+                    input.trim(),
+                    names.join(" or ")
+                ))
+            }
+        }
+    }
+
+    /// Picks the train from either a `TrainRef` argument or separate world and
+    /// expansion arguments, whichever the user gave.
+    pub fn select(
+        train: Option<TrainRef>,
+        world: Option<World>,
+        expac: Option<Expac>,
+    ) -> eyre::Result<(World, Expac)> {
+        match (train, world, expac) {
+            (Some(train), None, None) => Ok((train.world, train.expac)),
+            (None, Some(world), Some(expac)) => Ok((world, expac)),
+            (Some(_), _, _) => Err(eyre!(
+                "Give either `train` or `world` and `expac`, not both"
+            )),
+            (None, _, _) => Err(eyre!("Give either `train` or both `world` and `expac`")),
+        }
+    }
+}
+
+impl std::fmt::Display for TrainRef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.world, self.expac)
+    }
+}
+
+#[poise::async_trait]
+impl SlashArgument for TrainRef {
+    async fn extract(
+        _ctx: &serenity::Context,
+        _interaction: poise::ApplicationCommandOrAutocompleteInteraction<'_>,
+        value: &serenity::json::Value,
+    ) -> Result<Self, poise::SlashArgError> {
+        let str = value
+            .as_str()
+            .ok_or(poise::SlashArgError::CommandStructureMismatch(
+                "expected string",
+            ))?;
+        TrainRef::parse(str).map_err(|e| poise::SlashArgError::Parse {
+            error: Box::new(ErrWrap(eyre!(e))),
+            input: str.to_owned(),
+        })
+    }
+
+    fn create(builder: &mut poise::serenity_prelude::CreateApplicationCommandOption) {
+        builder.kind(poise::serenity_prelude::CommandOptionType::String);
+    }
+
+    fn choices() -> Vec<poise::CommandParameterChoice> {
+        Vec::new()
+    }
+}
 
 #[derive(Debug)]
 struct ErrWrap(eyre::Report);
@@ -31,33 +506,10 @@ impl SlashArgument for Timestamp {
             .ok_or(poise::SlashArgError::CommandStructureMismatch(
                 "expected string",
             ))?;
-        // We accept discord-formatted timestamps, or more accurately, anything between two colons.
-        // Also unformatted timestamps.
-        let split = str.split(":");
-        let timestamp = match *split.collect::<Vec<_>>() {
-            [t] => t,
-            [_, t, _] => t,
-            _ => {
-                return Err(poise::SlashArgError::Parse {
-                    error: Box::new(ErrWrap(eyre!("expected Discord timestamp"))),
-                    input: str.to_owned(),
-                })
-            }
-        };
-        match Utc.timestamp_opt(
-            timestamp.parse().map_err(|e| poise::SlashArgError::Parse {
-                error: Box::new(e),
-                input: str.to_owned(),
-            })?,
-            0,
-        ) {
-            chrono::LocalResult::None => Err(poise::SlashArgError::Parse {
-                error: Box::new(ErrWrap(eyre!("timestamp out of range"))),
-                input: str.to_owned(),
-            }),
-            chrono::LocalResult::Single(t) => Ok(Timestamp(t)),
-            chrono::LocalResult::Ambiguous(_, _) => panic!("this should never be ambiguous"),
-        }
+        Timestamp::parse(str, Utc::now()).map_err(|e| poise::SlashArgError::Parse {
+            error: Box::new(ErrWrap(eyre!(e))),
+            input: str.to_owned(),
+        })
     }
 
     fn create(builder: &mut poise::serenity_prelude::CreateApplicationCommandOption) {
@@ -69,8 +521,235 @@ impl SlashArgument for Timestamp {
     }
 }
 
-impl std::fmt::Display for Timestamp {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "<t:{}:f>", self.0.timestamp())
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utc(s: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(s).unwrap().with_timezone(&Utc)
+    }
+
+    fn offset(hours: i32, minutes: i32) -> FixedOffset {
+        FixedOffset::east_opt(hours * 3600 + minutes * 60).unwrap()
+    }
+
+    fn hm(hour: u32, minute: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(hour, minute, 0).unwrap()
+    }
+
+    #[test]
+    fn zone_abbreviations_and_offsets() {
+        assert_eq!(parse_zone("UTC"), Some(offset(0, 0)));
+        assert_eq!(parse_zone("est"), Some(offset(-5, 0)));
+        assert_eq!(parse_zone("UTC+2"), Some(offset(2, 0)));
+        assert_eq!(parse_zone("GMT-05:30"), Some(offset(-5, -30)));
+        assert_eq!(parse_zone("+0930"), Some(offset(9, 30)));
+        assert_eq!(parse_zone("-3"), Some(offset(-3, 0)));
+    }
+
+    #[test]
+    fn invalid_zones() {
+        for input in [
+            "", "+", "UTC+", "XYZ", "UTC+15", "+05:60", "++5", "+5:+3", "2",
+        ] {
+            assert_eq!(parse_zone(input), None, "{:?}", input);
+        }
+    }
+
+    #[test]
+    fn non_ascii_zones_are_rejected() {
+        for input in ["ÄST", "+ä", "+1ä", "UTC+äö", "-ää:30", "+１２"] {
+            assert_eq!(parse_zone(input), None, "{:?}", input);
+        }
+    }
+
+    #[test]
+    fn clock_times() {
+        assert_eq!(parse_clock("21:30"), Some(hm(21, 30)));
+        assert_eq!(parse_clock("21:30:15"), NaiveTime::from_hms_opt(21, 30, 15));
+        assert_eq!(parse_clock("9:30pm"), Some(hm(21, 30)));
+        assert_eq!(parse_clock("9 PM"), Some(hm(21, 0)));
+        assert_eq!(parse_clock("12am"), Some(hm(0, 0)));
+        assert_eq!(parse_clock("12pm"), Some(hm(12, 0)));
+    }
+
+    #[test]
+    fn invalid_clock_times() {
+        for input in ["21", "24:00", "13pm", "0am", "21:60", "1:2:3:4", "ä:30"] {
+            assert_eq!(parse_clock(input), None, "{:?}", input);
+        }
+    }
+
+    #[test]
+    fn durations() {
+        assert_eq!(parse_duration("10m"), Ok(Duration::minutes(10)));
+        assert_eq!(parse_duration("2 hours"), Ok(Duration::hours(2)));
+        assert_eq!(
+            parse_duration("1h 30m"),
+            Ok(Duration::hours(1) + Duration::minutes(30))
+        );
+        assert_eq!(
+            parse_duration("1d, 2h"),
+            Ok(Duration::days(1) + Duration::hours(2))
+        );
+    }
+
+    #[test]
+    fn invalid_durations() {
+        for input in ["", "m", "10", "10 parsecs", "99999h", "1h ä"] {
+            assert!(parse_duration(input).is_err(), "{:?}", input);
+        }
+    }
+
+    #[test]
+    fn exact_timestamps() {
+        let now = utc("2026-10-18T12:00:00Z");
+        let exact = |input| Timestamp::parse(input, now);
+        assert_eq!(exact("now"), Ok(Timestamp::Exact(now)));
+        assert_eq!(
+            exact("<t:1792316800:f>"),
+            Ok(Timestamp::Exact(utc("2026-10-18T09:46:40Z")))
+        );
+        assert_eq!(
+            exact("1792316800"),
+            Ok(Timestamp::Exact(utc("2026-10-18T09:46:40Z")))
+        );
+        assert_eq!(
+            exact("10m ago"),
+            Ok(Timestamp::Exact(utc("2026-10-18T11:50:00Z")))
+        );
+        assert_eq!(
+            exact("in 1h 30m"),
+            Ok(Timestamp::Exact(utc("2026-10-18T13:30:00Z")))
+        );
+        assert_eq!(
+            exact("2026-10-18T21:30Z"),
+            Ok(Timestamp::Exact(utc("2026-10-18T21:30:00Z")))
+        );
+        assert_eq!(
+            exact("2026-10-18 21:30+02:00"),
+            Ok(Timestamp::Exact(utc("2026-10-18T19:30:00Z")))
+        );
+    }
+
+    #[test]
+    fn local_timestamps() {
+        let now = utc("2026-10-18T12:00:00Z");
+        assert_eq!(
+            Timestamp::parse("21:30", now),
+            Ok(Timestamp::Local {
+                date: None,
+                time: hm(21, 30),
+                zone: None
+            })
+        );
+        assert_eq!(
+            Timestamp::parse("9:30pm EST", now),
+            Ok(Timestamp::Local {
+                date: None,
+                time: hm(21, 30),
+                zone: Some(offset(-5, 0))
+            })
+        );
+        assert_eq!(
+            Timestamp::parse("2026-10-20 08:00 UTC", now),
+            Ok(Timestamp::Local {
+                date: NaiveDate::from_ymd_opt(2026, 10, 20),
+                time: hm(8, 0),
+                zone: Some(offset(0, 0))
+            })
+        );
+    }
+
+    #[test]
+    fn invalid_timestamps() {
+        let now = utc("2026-10-18T12:00:00Z");
+        for input in ["", "10m", "tomorrow", "21:30 ÄST", "ä", "Tue 08:00 UTC"] {
+            assert!(Timestamp::parse(input, now).is_err(), "{:?}", input);
+        }
+    }
+
+    #[test]
+    fn dateless_times_resolve_to_the_nearest_occurrence() {
+        let now = utc("2026-10-18T21:45:00Z");
+        let time = Timestamp::parse("21:30", now).unwrap();
+        assert_eq!(
+            time.resolve_in(offset(0, 0), now),
+            Some(utc("2026-10-18T21:30:00Z"))
+        );
+        let time = Timestamp::parse("00:15", now).unwrap();
+        assert_eq!(
+            time.resolve_in(offset(0, 0), now),
+            Some(utc("2026-10-19T00:15:00Z"))
+        );
+    }
+
+    #[test]
+    fn dateless_times_resolve_to_the_next_occurrence() {
+        let now = utc("2026-10-18T21:45:00Z");
+        let time = Timestamp::parse("21:30", now).unwrap();
+        assert_eq!(
+            time.next_in(offset(0, 0), now),
+            Some(utc("2026-10-19T21:30:00Z"))
+        );
+        let time = Timestamp::parse("22:00", now).unwrap();
+        assert_eq!(
+            time.next_in(offset(0, 0), now),
+            Some(utc("2026-10-18T22:00:00Z"))
+        );
+    }
+
+    #[test]
+    fn given_zones_override_the_user_zone() {
+        let now = utc("2026-10-18T12:00:00Z");
+        let time = Timestamp::parse("9:30pm EST", now).unwrap();
+        assert_eq!(
+            time.resolve_in(offset(9, 0), now),
+            Some(utc("2026-10-18T02:30:00Z"))
+        );
+        assert_eq!(
+            time.next_in(offset(9, 0), now),
+            Some(utc("2026-10-19T02:30:00Z"))
+        );
+        let time = Timestamp::parse("2026-10-20 08:00", now).unwrap();
+        assert_eq!(
+            time.resolve_in(offset(2, 0), now),
+            Some(utc("2026-10-20T06:00:00Z"))
+        );
+    }
+
+    #[test]
+    fn times_far_from_now_are_rejected() {
+        let now = utc("2026-10-18T12:00:00Z");
+        for input in [
+            "+262143-12-31 23:59 UTC-14",
+            "-262144-01-01 00:00 UTC+14",
+            "+262143-12-31T23:59",
+            "2028-10-18 12:00",
+            "2024-10-18T12:00Z",
+            "in 9999d",
+            "9999d ago",
+            "<t:8210266876799:f>",
+            "-8334601228800",
+        ] {
+            assert!(Timestamp::parse(input, now).is_err(), "{:?}", input);
+        }
+        assert!(Timestamp::parse("2027-10-18 12:00 UTC", now).is_ok());
+        assert!(Timestamp::parse("in 300d", now).is_ok());
+    }
+
+    #[test]
+    fn extreme_local_times_resolve_to_none() {
+        let max = NaiveDate::MAX.and_hms_opt(23, 59, 0).unwrap();
+        let min = NaiveDate::MIN.and_hms_opt(0, 0, 0).unwrap();
+        assert_eq!(local_to_utc(offset(-14, 0), max), None);
+        assert_eq!(local_to_utc(offset(14, 0), min), None);
+        let time = Timestamp::Local {
+            date: Some(max.date()),
+            time: max.time(),
+            zone: Some(offset(-14, 0)),
+        };
+        assert_eq!(time.resolve_in(offset(0, 0), Utc::now()), None);
+        assert_eq!(time.next_in(offset(0, 0), Utc::now()), None);
     }
 }
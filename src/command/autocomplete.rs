@@ -0,0 +1,104 @@
+use poise::AutocompleteChoice;
+use sea_orm::{ColumnTrait, EntityTrait, QueryFilter, QueryOrder};
+
+use super::Context;
+use entity::locale::Locale;
+use entity::mark::Mark;
+use entity::s_rank::Zone;
+use entity::{train, Expac, World};
+
+/// Trains in order of when they were last used, most recent first.
+async fn recent_trains(ctx: Context<'_>) -> Vec<train::Model> {
+    let trains = train::Entity::find()
+        .filter(train::Column::UpdatedAt.is_not_null())
+        .order_by_desc(train::Column::UpdatedAt)
+        .all(&ctx.data().db)
+        .await;
+    // Suggestions in the wrong order are better than none at all.
+    trains.unwrap_or_else(|e| {
+        eprintln!("Warning: Unable to retrieve trains from DB: {}", e);
+        Vec::new()
+    })
+}
+
+/// Worlds matching what has been typed so far, most recently used first.
+pub async fn world(ctx: Context<'_>, partial: &str) -> Vec<String> {
+    let recent = recent_trains(ctx).await;
+    let mut worlds = World::matching(partial);
+    // Worlds that have never been used go last, in name order.
+    worlds.sort_by_key(|&world| {
+        let position = recent.iter().position(|t| t.world == world);
+        (position.is_none(), position)
+    });
+    worlds.into_iter().map(|world| world.to_string()).collect()
+}
+
+/// Expansions matching what has been typed so far, most recently used first,
+/// named in the user's language.
+pub async fn expac(ctx: Context<'_>, partial: &str) -> Vec<AutocompleteChoice<String>> {
+    let locale = ctx.locale().and_then(Locale::from_code).unwrap_or_default();
+    let recent = recent_trains(ctx).await;
+    let mut expacs = Expac::matching(partial);
+    expacs.sort_by_key(|&expac| {
+        let position = recent.iter().position(|t| t.expac == expac);
+        (position.is_none(), position)
+    });
+    expacs
+        .into_iter()
+        .map(|expac| AutocompleteChoice {
+            name: locale.expac(expac).to_owned(),
+            value: expac.to_string(),
+        })
+        .collect()
+}
+
+/// Existing trains matching what has been typed so far, most recently used
+/// first. Every word typed has to start a name of the world or expansion.
+pub async fn train(ctx: Context<'_>, partial: &str) -> Vec<AutocompleteChoice<String>> {
+    let locale = ctx.locale().and_then(Locale::from_code).unwrap_or_default();
+    let mut trains = match train::Entity::find().all(&ctx.data().db).await {
+        Ok(trains) => trains,
+        Err(e) => {
+            eprintln!("Warning: Unable to retrieve trains from DB: {}", e);
+            return Vec::new();
+        }
+    };
+    let words: Vec<_> = partial
+        .split(|c: char| c.is_whitespace() || c == '/' || c == ',')
+        .filter(|w| !w.is_empty())
+        .collect();
+    trains.retain(|t| {
+        words.iter().all(|word| {
+            World::matching(word).contains(&t.world) || Expac::matching(word).contains(&t.expac)
+        })
+    });
+    // Trains that have never been used go last.
+    trains.sort_by_key(|t| (std::cmp::Reverse(t.updated_at), t.world, t.expac));
+    trains
+        .into_iter()
+        .map(|t| AutocompleteChoice {
+            name: format!("{} {}", t.world, locale.expac(t.expac)),
+            value: format!("{}/{}", t.world, t.expac.short_name()),
+        })
+        .collect()
+}
+
+/// A-rank marks matching what has been typed so far.
+pub async fn mark(_ctx: Context<'_>, partial: &str) -> Vec<String> {
+    // Discord shows at most 25 suggestions.
+    Mark::matching(partial)
+        .into_iter()
+        .take(25)
+        .map(|mark| mark.name.to_owned())
+        .collect()
+}
+
+/// Zones with an S-rank matching what has been typed so far.
+pub async fn zone(_ctx: Context<'_>, partial: &str) -> Vec<String> {
+    // Discord shows at most 25 suggestions.
+    Zone::matching(partial)
+        .into_iter()
+        .take(25)
+        .map(|zone| zone.name.to_owned())
+        .collect()
+}
@@ -0,0 +1,103 @@
+use chrono::Utc;
+use eyre::eyre;
+use sea_orm::ActiveModelTrait;
+
+use super::argument::{self, TrainRef};
+use super::{autocomplete, Context};
+use crate::train::command::monitor_msg;
+use crate::train::refresh_train;
+use entity::mark::{Mark, Window};
+use entity::train::{self, Status};
+use entity::{mark_kill, Expac, World};
+
+/// Track individual A-rank marks.
+#[poise::command(slash_command, subcommands("killed", "marks"))]
+pub async fn hunt(_ctx: Context<'_>) -> eyre::Result<()> {
+    Err(eyre!("unsupported"))
+}
+
+/// Report a single mark as killed
+#[poise::command(slash_command)]
+pub async fn killed(
+    ctx: Context<'_>,
+    #[autocomplete = "autocomplete::mark"]
+    #[description = "A-rank mark"]
+    mark: Mark,
+    #[autocomplete = "autocomplete::world"]
+    #[description = "World server"]
+    world: World,
+    #[description = "When it was killed, e.g. \"10m ago\" or \"21:30\" (default: now)"]
+    kill_time: Option<argument::Timestamp>,
+) -> eyre::Result<()> {
+    let kill_time = match kill_time {
+        Some(kill_time) => kill_time.resolve(ctx).await?,
+        None => Utc::now(),
+    };
+    let db = &ctx.data().db;
+    mark_kill::record(db, world, mark, kill_time).await?;
+    let mut train = train::find_or_create(db, world, mark.expac).await?;
+    if train.mark_killed(kill_time) {
+        train = train::ActiveModel::from(train).update(db).await?;
+    }
+
+    ctx.defer().await?;
+    let success = refresh_train(ctx, &train).await;
+    let text = format!(
+        "{} on {} killed at <t:{}:f>; its window opens <t:{}:R>",
+        mark,
+        world,
+        kill_time.timestamp(),
+        (kill_time + Mark::min_respawn()).timestamp()
+    );
+    ctx.say(monitor_msg(ctx, text, success).await?).await?;
+    Ok(())
+}
+
+/// One mark's respawn window, as listed by `/hunt marks`.
+fn window_line(mark: Mark, window: Window) -> String {
+    match window {
+        Window::Unknown => format!("{} **{}**: no kill reported", Status::Unknown.emoji(), mark),
+        Window::Closed { opens } => format!(
+            "{} **{}**: window opens <t:{}:R>",
+            Status::Waiting.emoji(),
+            mark,
+            opens.timestamp()
+        ),
+        Window::Open { forced } => format!(
+            "🟢 **{}**: in window, forced <t:{}:R>",
+            mark,
+            forced.timestamp()
+        ),
+        Window::Forced { since } => format!("✅ **{}**: forced <t:{}:R>", mark, since.timestamp()),
+    }
+}
+
+/// Show which marks of a train are in their respawn window
+#[poise::command(slash_command, ephemeral)]
+pub async fn marks(
+    ctx: Context<'_>,
+    #[autocomplete = "autocomplete::train"]
+    #[description = "Train, e.g. \"Seraph EW\", instead of world and expansion"]
+    train: Option<TrainRef>,
+    #[autocomplete = "autocomplete::world"]
+    #[description = "World server"]
+    world: Option<World>,
+    #[autocomplete = "autocomplete::expac"]
+    #[description = "Expansion"]
+    expac: Option<Expac>,
+) -> eyre::Result<()> {
+    let (world, expac) = TrainRef::select(train, world, expac)?;
+    let kills = mark_kill::for_train(&ctx.data().db, world, expac).await?;
+    let now = Utc::now();
+    let lines: Vec<_> = Mark::of_expac(expac)
+        .map(|mark| window_line(mark, Mark::window(kills.get(mark.name).copied(), now)))
+        .collect();
+    ctx.send(|m| {
+        m.embed(|e| {
+            e.title(format!("{} {} Marks", world, expac.short_name()))
+                .description(lines.join("\n"))
+        })
+    })
+    .await?;
+    Ok(())
+}
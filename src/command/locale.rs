@@ -0,0 +1,68 @@
+use std::collections::HashMap;
+
+use super::Data;
+use entity::locale::Locale;
+
+mod de;
+mod fr;
+mod ja;
+
+/// A translated name and description, keyed by the command's qualified name,
+/// e.g. `train monitors list`, with `:param` appended for a parameter. A key
+/// of just `:param` applies to that parameter in every command.
+type Entry = (&'static str, &'static str, &'static str);
+
+const CATALOGS: &[(Locale, &[Entry])] = &[
+    (Locale::Japanese, ja::COMMANDS),
+    (Locale::French, fr::COMMANDS),
+    (Locale::German, de::COMMANDS),
+];
+
+/// Fills in the localized names and descriptions shown by Discord. Anything
+/// without a translation is shown in English.
+pub fn localize(commands: &mut [poise::Command<Data, eyre::Report>]) {
+    for command in commands {
+        let path = command.name.clone();
+        localize_command(command, &path);
+    }
+}
+
+fn localize_command(command: &mut poise::Command<Data, eyre::Report>, path: &str) {
+    for &(locale, catalog) in CATALOGS {
+        let find = |key: &str| catalog.iter().find(|(k, ..)| *k == key);
+        if let Some(entry) = find(path) {
+            insert(
+                &mut command.name_localizations,
+                &mut command.description_localizations,
+                locale,
+                entry,
+            );
+        }
+        for param in &mut command.parameters {
+            let entry = find(&format!("{}:{}", path, param.name))
+                .or_else(|| find(&format!(":{}", param.name)));
+            if let Some(entry) = entry {
+                insert(
+                    &mut param.name_localizations,
+                    &mut param.description_localizations,
+                    locale,
+                    entry,
+                );
+            }
+        }
+    }
+    for subcommand in &mut command.subcommands {
+        let path = format!("{} {}", path, subcommand.name);
+        localize_command(subcommand, &path);
+    }
+}
+
+fn insert(
+    names: &mut HashMap<String, String>,
+    descriptions: &mut HashMap<String, String>,
+    locale: Locale,
+    &(_, name, description): &Entry,
+) {
+    names.insert(locale.code().to_owned(), name.to_owned());
+    descriptions.insert(locale.code().to_owned(), description.to_owned());
+}
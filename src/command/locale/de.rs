@@ -0,0 +1,447 @@
+use super::Entry;
+
+pub(super) const COMMANDS: &[Entry] = &[
+    ("train", "zug", "Befehle für Hunt-Züge"),
+    (
+        "train scout",
+        "scouten",
+        "Einen Zug als gescoutet markieren",
+    ),
+    ("train start", "starten", "Einen Zug als laufend markieren"),
+    (
+        "train done",
+        "fertig",
+        "Einen Zug als abgeschlossen markieren",
+    ),
+    (
+        "train sniped",
+        "gesnipt",
+        "Markieren, dass die Marks außerhalb eines Trains getötet wurden",
+    ),
+    (
+        "train abandon",
+        "abbrechen",
+        "Markieren, dass ein Train vor allen Marks beendet wurde",
+    ),
+    (
+        "train batch",
+        "mehrere",
+        "Mehrere Züge einer Welt auf einmal aktualisieren",
+    ),
+    (
+        "train batch done",
+        "fertig",
+        "Mehrere Züge einer Welt als abgeschlossen markieren",
+    ),
+    (
+        "train batch start",
+        "starten",
+        "Mehrere Züge einer Welt als laufend markieren",
+    ),
+    (
+        "train batch reset",
+        "zurücksetzen",
+        "Status und Zeiten mehrerer Züge einer Welt löschen",
+    ),
+    ("train maintenance", "wartung", "Serverwartung erfassen"),
+    (
+        "train maintenance start",
+        "beginn",
+        "Alle Züge auf den Spawn nach der Wartung setzen",
+    ),
+    (
+        "train maintenance end",
+        "ende",
+        "Spawnzeit nach der Wartung festlegen",
+    ),
+    ("train schedule", "fahrplan", "Züge im Voraus planen"),
+    (
+        "train schedule add",
+        "hinzufügen",
+        "Einen Zug planen, der bis zum Start auf Monitoren und Dashboards erscheint",
+    ),
+    (
+        "train schedule cancel",
+        "absagen",
+        "Einen geplanten Zug absagen oder einen Termin eines wiederkehrenden Zuges auslassen",
+    ),
+    (
+        "train schedule pause",
+        "pausieren",
+        "Einen wiederkehrenden Zug pausieren oder fortsetzen",
+    ),
+    (
+        "train schedule list",
+        "liste",
+        "Wiederkehrende und geplante Züge auflisten",
+    ),
+    (
+        "train alerts",
+        "alarme",
+        "Alarme vor und beim Erzwingen von Zügen",
+    ),
+    (
+        "train alerts set",
+        "festlegen",
+        "In diesem Kanal vor oder beim Erzwingen von Zügen alarmieren",
+    ),
+    (
+        "train alerts remove",
+        "entfernen",
+        "Einen Alarm in diesem Kanal nicht mehr posten",
+    ),
+    (
+        "train alerts list",
+        "liste",
+        "Kanäle auflisten, in denen Alarme gepostet werden",
+    ),
+    (
+        "train alerts subscribe",
+        "abonnieren",
+        "Die von einem Alarm erwähnten Rollen nehmen oder abgeben",
+    ),
+    (
+        "train expiry",
+        "ablauf",
+        "Festlegen, wie lange ein Status ohne Aktualisierung gültig bleibt",
+    ),
+    (
+        "train expiry show",
+        "anzeigen",
+        "Anzeigen, wie lange jeder Status ohne Aktualisierung gilt",
+    ),
+    (
+        "train expiry set",
+        "festlegen",
+        "Festlegen, wie viele Stunden ein Status ohne Aktualisierung gilt",
+    ),
+    (
+        "train expiry reset",
+        "zurücksetzen",
+        "Standardablauf für Status wiederherstellen",
+    ),
+    (
+        "train create_monitor",
+        "monitor_erstellen",
+        "Einen Statusbeitrag für einen Zug hinzufügen",
+    ),
+    (
+        "train create_dashboard",
+        "dashboard_erstellen",
+        "Ein Zug-Dashboard hinzufügen",
+    ),
+    (
+        "train monitors",
+        "monitore",
+        "Bestehende Statusbeiträge verwalten",
+    ),
+    ("train monitors list", "liste", "Statusbeiträge auflisten"),
+    (
+        "train monitors delete",
+        "löschen",
+        "Einen Statusbeitrag löschen",
+    ),
+    (
+        "train monitors retarget",
+        "umstellen",
+        "Den Zug eines Statusbeitrags ändern",
+    ),
+    (
+        "train dashboards",
+        "dashboards",
+        "Bestehende Dashboards verwalten",
+    ),
+    ("train dashboards list", "liste", "Dashboards auflisten"),
+    (
+        "train dashboards configure",
+        "konfigurieren",
+        "Ändern, welche Züge ein Dashboard wie zeigt",
+    ),
+    (
+        "train dashboards delete",
+        "löschen",
+        "Ein Dashboard löschen",
+    ),
+    (
+        "train theme",
+        "design",
+        "Anpassen, wie Status angezeigt werden",
+    ),
+    (
+        "train theme show",
+        "anzeigen",
+        "Emoji und Farbe jedes Status anzeigen",
+    ),
+    (
+        "train theme set",
+        "festlegen",
+        "Emoji oder Farbe eines Status festlegen",
+    ),
+    (
+        "train theme reset",
+        "zurücksetzen",
+        "Standard-Emoji und -Farbe wiederherstellen",
+    ),
+    (
+        "train template",
+        "vorlage",
+        "Texte von Monitoren, Dashboards und Antworten anpassen",
+    ),
+    (
+        "train template list",
+        "liste",
+        "Alle Vorlagen und ihre Variablen anzeigen",
+    ),
+    (
+        "train template set",
+        "festlegen",
+        "Den Text einer Vorlage ändern",
+    ),
+    (
+        "train template reset",
+        "zurücksetzen",
+        "Standardtext wiederherstellen",
+    ),
+    (
+        "train language",
+        "sprache",
+        "Sprache der Monitore und Dashboards festlegen",
+    ),
+    (
+        "train timezone",
+        "zeitzone",
+        "Zeitzone für Zeiten ohne Zonenangabe festlegen",
+    ),
+    (
+        ":train",
+        "zug",
+        "Zug, z. B. „Seraph EW“, statt Welt und Erweiterung",
+    ),
+    (
+        "maintenance",
+        "wartung",
+        "Serverwartung planen, die beim Eintreten alle Züge zurücksetzt",
+    ),
+    (
+        "maintenance schedule",
+        "planen",
+        "Eine Wartung planen, die automatisch auf alle Züge angewendet wird",
+    ),
+    (
+        "maintenance cancel",
+        "absagen",
+        "Eine noch nicht begonnene Wartung absagen",
+    ),
+    (
+        "maintenance list",
+        "liste",
+        "Geplante Wartungen und Ankündigungskanäle anzeigen",
+    ),
+    (
+        "maintenance notices",
+        "ankündigungen",
+        "Beginn und Ende von Wartungen in diesem Kanal ankündigen",
+    ),
+    ("hunt", "jagd", "Einzelne A-Rang-Marks verfolgen"),
+    ("hunt killed", "getötet", "Einen getöteten Mark melden"),
+    (
+        "hunt marks",
+        "marks",
+        "Anzeigen, welche Marks eines Trains im Spawnfenster sind",
+    ),
+    ("srank", "s_rang", "S-Rang-Spawnfenster verfolgen"),
+    ("srank killed", "getötet", "Einen getöteten S-Rang melden"),
+    (
+        "srank status",
+        "status",
+        "S-Rang-Fenster einer Welt anzeigen",
+    ),
+    (
+        "srank set",
+        "festlegen",
+        "Spawnfenster und Spawnbedingungen eines S-Rangs festlegen",
+    ),
+    (
+        "srank create_dashboard",
+        "dashboard_erstellen",
+        "Ein S-Rang-Dashboard hinzufügen",
+    ),
+    (":world", "welt", "Welt"),
+    (":expac", "erweiterung", "Erweiterung"),
+    (
+        ":map_link",
+        "karte",
+        "Link zu einer Karte oder Nachricht mit den Positionen",
+    ),
+    (
+        "train scout:map_link",
+        "karte",
+        "Link zu einer Karte oder Nachricht mit den Positionen (leer lassen, um sie zu entfernen)",
+    ),
+    (
+        ":completion_time",
+        "endzeit",
+        "Zeitpunkt des Abschlusses (Standard: jetzt)",
+    ),
+    (
+        ":force_time",
+        "erzwungen",
+        "Zeitpunkt des erzwungenen Spawns, nicht zusammen mit endzeit",
+    ),
+    (
+        ":sticky",
+        "dauerhaft",
+        "Beitrag neu posten, wenn er gelöscht wird",
+    ),
+    (":id", "id", "ID aus dem Listenbefehl"),
+    (
+        ":data_center",
+        "datenzentrum",
+        "Nur dieses Datenzentrum zeigen (Standard: alle)",
+    ),
+    (
+        ":overview",
+        "übersicht",
+        "Anzahl pro Datenzentrum statt einzelner Züge zeigen",
+    ),
+    (":worlds", "welten", "Kommagetrennte Welten oder „all“"),
+    (
+        ":expacs",
+        "erweiterungen",
+        "Kommagetrennte Erweiterungen oder „all“",
+    ),
+    (":transposed", "transponiert", "Welten als Zeilen zeigen"),
+    (
+        ":sort_by_force",
+        "nach_spawn",
+        "Welten nach nächstem erzwungenem Spawn sortieren",
+    ),
+    (
+        ":compact",
+        "kompakt",
+        "Erklärtext weglassen und kurze Bezeichnungen nutzen",
+    ),
+    (":status", "status", "Betroffener Status"),
+    (":emoji", "emoji", "Unicode- oder Server-Emoji"),
+    (":colour", "farbe", "Embed-Farbe, z. B. #FF8800"),
+    (":key", "vorlage", "Betroffene Vorlage"),
+    (":text", "text", "Neuer Text mit Variablen wie {world}"),
+    (
+        ":language",
+        "sprache",
+        "Sprache der Monitore und Dashboards",
+    ),
+    (
+        ":zone",
+        "zone",
+        "Abkürzung oder Versatz, z. B. CET oder UTC+2",
+    ),
+    (
+        "train batch done:expacs",
+        "erweiterungen",
+        "Zu aktualisierende Erweiterungen (durch Kommas getrennt, oder all)",
+    ),
+    (
+        "train batch start:expacs",
+        "erweiterungen",
+        "Zu aktualisierende Erweiterungen (durch Kommas getrennt, oder all)",
+    ),
+    (
+        "train batch reset:expacs",
+        "erweiterungen",
+        "Zu aktualisierende Erweiterungen (durch Kommas getrennt, oder all)",
+    ),
+    (
+        ":end_time",
+        "endzeit",
+        "Wann die Server wieder online waren (Standard: jetzt)",
+    ),
+    (":start", "beginn", "Beginn der Wartung"),
+    (":end", "ende", "Wann die Server wieder online sind"),
+    (
+        ":enabled",
+        "aktiv",
+        "Ob Wartungen hier angekündigt werden (Standard: ja)",
+    ),
+    (
+        ":time",
+        "zeit",
+        "Startzeit des Zuges, z. B. 21:30 oder in 2h",
+    ),
+    (
+        ":conductor",
+        "zugführer",
+        "Wer den Zug führt (Standard: du)",
+    ),
+    (":notes", "notizen", "Was Mitfahrende wissen sollten"),
+    (
+        ":remind",
+        "erinnerung",
+        "Minuten vor dem Start für eine Erinnerung hier (Standard: 15)",
+    ),
+    (
+        ":location",
+        "treffpunkt",
+        "Startort des Zuges, auf seinem Discord-Event angezeigt",
+    ),
+    (
+        ":repeat_weeks",
+        "wiederholen_wochen",
+        "Den Zug alle N Wochen wiederholen",
+    ),
+    (
+        ":series",
+        "serie",
+        "Serien-ID, wie von /train schedule list angezeigt",
+    ),
+    (
+        ":paused",
+        "pausiert",
+        "Ob die Planung pausiert wird (Standard: ja)",
+    ),
+    (":kind", "art", "Welcher Alarm"),
+    (":role", "rolle", "Zu erwähnende Rolle"),
+    (
+        ":minutes",
+        "minuten",
+        "Minuten vor dem Erzwingen für soon-Alarme (Standard: 30)",
+    ),
+    (
+        ":subscribed",
+        "abonniert",
+        "Ob du erwähnt werden willst (Standard: ja)",
+    ),
+    (
+        ":hours",
+        "stunden",
+        "Stunden ohne Aktualisierung, oder 0 für nie",
+    ),
+    (
+        ":kill_time",
+        "todeszeit",
+        "Wann die Marks getötet wurden (Standard: jetzt)",
+    ),
+    (
+        ":marks_left",
+        "verbleibende_marks",
+        "Noch lebende Marks, falls welche getötet wurden (Standard: keine getötet)",
+    ),
+    (":mark", "mark", "A-Rang-Mark"),
+    ("srank killed:zone", "gebiet", "Gebiet des S-Rangs"),
+    ("srank set:zone", "gebiet", "Gebiet des S-Rangs"),
+    (
+        "srank set:notes",
+        "bedingungen",
+        "Was für den Spawn nötig ist (\"-\" zum Löschen)",
+    ),
+    (
+        ":min_hours",
+        "min_stunden",
+        "Stunden nach einem Kill, bevor er wieder spawnen kann",
+    ),
+    (
+        ":max_hours",
+        "max_stunden",
+        "Stunden nach einem Kill, nach denen er sicher wieder spawnen kann",
+    ),
+];
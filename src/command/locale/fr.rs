@@ -0,0 +1,467 @@
+use super::Entry;
+
+pub(super) const COMMANDS: &[Entry] = &[
+    ("train", "train", "Commandes des trains de chasse"),
+    ("train scout", "repérer", "Marquer un train comme repéré"),
+    ("train start", "lancer", "Marquer un train comme en cours"),
+    ("train done", "terminer", "Marquer un train comme terminé"),
+    (
+        "train sniped",
+        "volé",
+        "Indiquer que les marques ont été tuées hors d'un train",
+    ),
+    (
+        "train abandon",
+        "abandonner",
+        "Indiquer qu'un train s'est arrêté avant d'avoir tué toutes les marques",
+    ),
+    (
+        "train batch",
+        "lot",
+        "Mettre à jour plusieurs trains d'un monde à la fois",
+    ),
+    (
+        "train batch done",
+        "terminer",
+        "Marquer plusieurs trains d'un monde comme terminés",
+    ),
+    (
+        "train batch start",
+        "lancer",
+        "Marquer plusieurs trains d'un monde comme en cours",
+    ),
+    (
+        "train batch reset",
+        "réinitialiser",
+        "Effacer le statut et les heures de plusieurs trains d'un monde",
+    ),
+    (
+        "train maintenance",
+        "maintenance",
+        "Suivre la maintenance des serveurs",
+    ),
+    (
+        "train maintenance start",
+        "début",
+        "Mettre tous les trains en attente de l'apparition post-maintenance",
+    ),
+    (
+        "train maintenance end",
+        "fin",
+        "Fixer l'heure d'apparition après la maintenance",
+    ),
+    (
+        "train schedule",
+        "planning",
+        "Planifier des trains à l'avance",
+    ),
+    (
+        "train schedule add",
+        "ajouter",
+        "Planifier un train, affiché sur ses moniteurs et tableaux jusqu'à son départ",
+    ),
+    (
+        "train schedule cancel",
+        "annuler",
+        "Annuler un train planifié, ou sauter une occurrence d'un train récurrent",
+    ),
+    (
+        "train schedule pause",
+        "pause",
+        "Suspendre ou reprendre un train récurrent",
+    ),
+    (
+        "train schedule list",
+        "liste",
+        "Lister les trains récurrents et planifiés",
+    ),
+    (
+        "train alerts",
+        "alertes",
+        "Alertes avant et au moment où les trains sont forcés",
+    ),
+    (
+        "train alerts set",
+        "définir",
+        "Poster une alerte dans ce salon avant ou quand les trains sont forcés",
+    ),
+    (
+        "train alerts remove",
+        "retirer",
+        "Ne plus poster une alerte dans ce salon",
+    ),
+    (
+        "train alerts list",
+        "liste",
+        "Lister les salons où les alertes sont postées",
+    ),
+    (
+        "train alerts subscribe",
+        "abonnement",
+        "Prendre ou retirer les rôles mentionnés par une alerte",
+    ),
+    (
+        "train expiry",
+        "expiration",
+        "Choisir combien de temps un statut reste valable sans mise à jour",
+    ),
+    (
+        "train expiry show",
+        "afficher",
+        "Afficher la durée de validité de chaque statut",
+    ),
+    (
+        "train expiry set",
+        "définir",
+        "Définir combien d'heures un statut reste valable sans mise à jour",
+    ),
+    (
+        "train expiry reset",
+        "réinitialiser",
+        "Revenir à l'expiration par défaut des statuts",
+    ),
+    (
+        "train create_monitor",
+        "créer_moniteur",
+        "Ajouter un message de suivi d'un train",
+    ),
+    (
+        "train create_dashboard",
+        "créer_tableau",
+        "Ajouter un tableau de bord des trains",
+    ),
+    (
+        "train monitors",
+        "moniteurs",
+        "Gérer les messages de suivi existants",
+    ),
+    (
+        "train monitors list",
+        "liste",
+        "Lister les messages de suivi",
+    ),
+    (
+        "train monitors delete",
+        "supprimer",
+        "Supprimer un message de suivi",
+    ),
+    (
+        "train monitors retarget",
+        "recibler",
+        "Changer le train suivi par un moniteur",
+    ),
+    (
+        "train dashboards",
+        "tableaux",
+        "Gérer les tableaux de bord existants",
+    ),
+    (
+        "train dashboards list",
+        "liste",
+        "Lister les tableaux de bord",
+    ),
+    (
+        "train dashboards configure",
+        "configurer",
+        "Changer les trains affichés par un tableau de bord et leur présentation",
+    ),
+    (
+        "train dashboards delete",
+        "supprimer",
+        "Supprimer un tableau de bord",
+    ),
+    (
+        "train theme",
+        "thème",
+        "Personnaliser l'affichage des statuts",
+    ),
+    (
+        "train theme show",
+        "afficher",
+        "Afficher l'emoji et la couleur de chaque statut",
+    ),
+    (
+        "train theme set",
+        "définir",
+        "Définir l'emoji ou la couleur d'un statut",
+    ),
+    (
+        "train theme reset",
+        "réinitialiser",
+        "Revenir à l'emoji et à la couleur par défaut",
+    ),
+    (
+        "train template",
+        "modèle",
+        "Personnaliser le texte des moniteurs, tableaux et réponses",
+    ),
+    (
+        "train template list",
+        "liste",
+        "Afficher chaque modèle et ses variables",
+    ),
+    (
+        "train template set",
+        "définir",
+        "Changer le texte d'un modèle",
+    ),
+    (
+        "train template reset",
+        "réinitialiser",
+        "Revenir au texte par défaut",
+    ),
+    (
+        "train language",
+        "langue",
+        "Choisir la langue des moniteurs et tableaux de bord",
+    ),
+    (
+        "train timezone",
+        "fuseau_horaire",
+        "Choisir le fuseau des heures saisies sans fuseau",
+    ),
+    (
+        ":train",
+        "train",
+        "Train, p. ex. « Seraph EW », au lieu du monde et de l'extension",
+    ),
+    (
+        "maintenance",
+        "maintenance",
+        "Planifier une maintenance des serveurs, qui réinitialise tous les trains",
+    ),
+    (
+        "maintenance schedule",
+        "planifier",
+        "Planifier une maintenance appliquée automatiquement à tous les trains",
+    ),
+    (
+        "maintenance cancel",
+        "annuler",
+        "Annuler une maintenance qui n'a pas encore commencé",
+    ),
+    (
+        "maintenance list",
+        "liste",
+        "Lister les maintenances prévues et les salons d'annonce",
+    ),
+    (
+        "maintenance notices",
+        "annonces",
+        "Annoncer le début et la fin des maintenances dans ce salon",
+    ),
+    ("hunt", "chasse", "Suivre chaque marque de rang A"),
+    ("hunt killed", "tuée", "Signaler une marque tuée"),
+    (
+        "hunt marks",
+        "marques",
+        "Afficher quelles marques d'un train sont dans leur fenêtre d'apparition",
+    ),
+    (
+        "srank",
+        "rang_s",
+        "Suivre les fenêtres d'apparition des rangs S",
+    ),
+    ("srank killed", "tué", "Signaler un rang S tué"),
+    (
+        "srank status",
+        "état",
+        "Afficher les fenêtres des rangs S d'un monde",
+    ),
+    (
+        "srank set",
+        "définir",
+        "Définir la fenêtre et les conditions d'apparition d'un rang S",
+    ),
+    (
+        "srank create_dashboard",
+        "créer_tableau",
+        "Ajouter un tableau de bord des rangs S",
+    ),
+    (":world", "monde", "Monde"),
+    (":expac", "extension", "Extension"),
+    (
+        ":map_link",
+        "carte",
+        "Lien vers une carte ou un message avec les positions",
+    ),
+    (
+        "train scout:map_link",
+        "carte",
+        "Lien vers une carte ou un message avec les positions (vide pour effacer la carte)",
+    ),
+    (
+        ":completion_time",
+        "heure_fin",
+        "Heure de fin (par défaut : maintenant)",
+    ),
+    (
+        ":force_time",
+        "heure_forcée",
+        "Heure d'apparition forcée, incompatible avec heure_fin",
+    ),
+    (
+        ":sticky",
+        "permanent",
+        "Republier le message s'il est supprimé",
+    ),
+    (":id", "id", "Identifiant affiché par la commande liste"),
+    (
+        ":data_center",
+        "centre_de_données",
+        "Afficher seulement ce centre de données (par défaut : tous)",
+    ),
+    (
+        ":overview",
+        "aperçu",
+        "Afficher le nombre de trains par centre de données",
+    ),
+    (
+        ":worlds",
+        "mondes",
+        "Mondes à afficher, séparés par des virgules, ou « all »",
+    ),
+    (
+        ":expacs",
+        "extensions",
+        "Extensions à afficher, séparées par des virgules, ou « all »",
+    ),
+    (":transposed", "transposé", "Afficher les mondes en lignes"),
+    (
+        ":sort_by_force",
+        "tri_forcé",
+        "Trier les mondes par heure d'apparition forcée",
+    ),
+    (
+        ":compact",
+        "compact",
+        "Omettre les explications et utiliser des libellés courts",
+    ),
+    (":status", "statut", "Statut concerné"),
+    (":emoji", "emoji", "Emoji Unicode ou du serveur"),
+    (":colour", "couleur", "Couleur de l'embed, p. ex. #FF8800"),
+    (":key", "modèle", "Modèle concerné"),
+    (
+        ":text",
+        "texte",
+        "Nouveau texte, avec des variables comme {world}",
+    ),
+    (
+        ":language",
+        "langue",
+        "Langue des moniteurs et tableaux de bord",
+    ),
+    (
+        ":zone",
+        "fuseau",
+        "Abréviation ou décalage, p. ex. CET ou UTC+2",
+    ),
+    (
+        "train batch done:expacs",
+        "extensions",
+        "Extensions à mettre à jour (séparées par des virgules, ou all)",
+    ),
+    (
+        "train batch start:expacs",
+        "extensions",
+        "Extensions à mettre à jour (séparées par des virgules, ou all)",
+    ),
+    (
+        "train batch reset:expacs",
+        "extensions",
+        "Extensions à mettre à jour (séparées par des virgules, ou all)",
+    ),
+    (
+        ":end_time",
+        "heure_de_fin",
+        "Heure de retour des serveurs (par défaut : maintenant)",
+    ),
+    (":start", "début", "Début de la maintenance"),
+    (":end", "fin", "Heure de retour des serveurs"),
+    (
+        ":enabled",
+        "activé",
+        "Annoncer ou non les maintenances ici (par défaut : oui)",
+    ),
+    (
+        ":time",
+        "heure",
+        "Heure de départ du train, p. ex. 21:30 ou in 2h",
+    ),
+    (
+        ":conductor",
+        "conducteur",
+        "Qui conduira le train (par défaut : vous)",
+    ),
+    (":notes", "notes", "Ce que les passagers doivent savoir"),
+    (
+        ":remind",
+        "rappel",
+        "Minutes avant le départ pour poster un rappel ici (par défaut : 15)",
+    ),
+    (
+        ":location",
+        "lieu",
+        "Lieu de départ du train, affiché sur son événement Discord",
+    ),
+    (
+        ":repeat_weeks",
+        "répéter_semaines",
+        "Refaire le train toutes les N semaines",
+    ),
+    (
+        ":series",
+        "série",
+        "ID de la série, affiché par /train schedule list",
+    ),
+    (
+        ":paused",
+        "suspendu",
+        "Suspendre la planification (par défaut : oui)",
+    ),
+    (":kind", "type", "Quelle alerte"),
+    (":role", "rôle", "Rôle à mentionner"),
+    (
+        ":minutes",
+        "minutes",
+        "Minutes avant le forçage pour les alertes soon (par défaut : 30)",
+    ),
+    (
+        ":subscribed",
+        "abonné",
+        "Être mentionné ou non (par défaut : oui)",
+    ),
+    (
+        ":hours",
+        "heures",
+        "Heures sans mise à jour, ou 0 pour ne jamais expirer",
+    ),
+    (
+        ":kill_time",
+        "heure_de_mort",
+        "Quand les marques ont été tuées (par défaut : maintenant)",
+    ),
+    (
+        ":marks_left",
+        "marques_restantes",
+        "Marques encore en vie, si certaines ont été tuées (par défaut : aucune tuée)",
+    ),
+    (":mark", "marque", "Marque de rang A"),
+    ("srank killed:zone", "zone", "Zone du rang S"),
+    ("srank set:zone", "zone", "Zone du rang S"),
+    (
+        "srank set:notes",
+        "conditions",
+        "Ce qu'il faut faire pour qu'il apparaisse (« - » pour effacer)",
+    ),
+    (
+        ":min_hours",
+        "heures_min",
+        "Heures après une mort avant qu'il puisse réapparaître",
+    ),
+    (
+        ":max_hours",
+        "heures_max",
+        "Heures après une mort au bout desquelles il peut sûrement réapparaître",
+    ),
+];
@@ -0,0 +1,355 @@
+use super::Entry;
+
+pub(super) const COMMANDS: &[Entry] = &[
+    ("train", "トレイン", "モブハントトレインのコマンド"),
+    ("train scout", "偵察", "トレインを偵察済みにする"),
+    ("train start", "開始", "トレインを進行中にする"),
+    ("train done", "完了", "トレインを完了にする"),
+    (
+        "train sniped",
+        "横取り",
+        "トレイン外でモブが倒されたことを記録",
+    ),
+    (
+        "train abandon",
+        "中止",
+        "全モブを倒す前に終了したトレインを記録",
+    ),
+    (
+        "train batch",
+        "一括",
+        "同じワールドの複数のトレインをまとめて更新する",
+    ),
+    (
+        "train batch done",
+        "完了",
+        "ワールドの複数のトレインを完了にする",
+    ),
+    (
+        "train batch start",
+        "開始",
+        "ワールドの複数のトレインを進行中にする",
+    ),
+    (
+        "train batch reset",
+        "リセット",
+        "ワールドの複数のトレインの状態と時刻を消去する",
+    ),
+    (
+        "train maintenance",
+        "メンテナンス",
+        "サーバーメンテナンスを記録する",
+    ),
+    (
+        "train maintenance start",
+        "開始",
+        "全トレインをメンテ明け湧き待ちにする",
+    ),
+    (
+        "train maintenance end",
+        "終了",
+        "メンテ明けの湧き時刻を設定する",
+    ),
+    ("train schedule", "予定", "トレインを前もって計画する"),
+    (
+        "train schedule add",
+        "追加",
+        "トレインを計画し、開始までモニターとダッシュボードに表示する",
+    ),
+    (
+        "train schedule cancel",
+        "取消",
+        "計画したトレインを取り消す、または定期トレインの1回を飛ばす",
+    ),
+    (
+        "train schedule pause",
+        "一時停止",
+        "定期トレインの計画を止める、または再開する",
+    ),
+    (
+        "train schedule list",
+        "一覧",
+        "定期トレインと予定のトレインを一覧表示する",
+    ),
+    ("train alerts", "アラート", "強制湧き前後のアラート"),
+    (
+        "train alerts set",
+        "設定",
+        "強制湧きの前または時点でこのチャンネルにアラートを投稿する",
+    ),
+    (
+        "train alerts remove",
+        "削除",
+        "このチャンネルへのアラート投稿をやめる",
+    ),
+    (
+        "train alerts list",
+        "一覧",
+        "アラートを投稿するチャンネルを表示する",
+    ),
+    (
+        "train alerts subscribe",
+        "購読",
+        "アラートで通知されるロールを付ける、または外す",
+    ),
+    (
+        "train expiry",
+        "期限",
+        "更新がない状態が古くなるまでの時間を設定",
+    ),
+    (
+        "train expiry show",
+        "表示",
+        "各状態が更新なしで続く時間を表示",
+    ),
+    ("train expiry set", "設定", "状態が更新なしで続く時間を設定"),
+    ("train expiry reset", "リセット", "状態の期限を既定に戻す"),
+    (
+        "train create_monitor",
+        "モニター作成",
+        "トレインの状態を表示する投稿を追加する",
+    ),
+    (
+        "train create_dashboard",
+        "ダッシュボード作成",
+        "トレインのダッシュボードを追加する",
+    ),
+    ("train monitors", "モニター", "既存のモニター投稿を管理する"),
+    ("train monitors list", "一覧", "モニター投稿を一覧表示する"),
+    ("train monitors delete", "削除", "モニター投稿を削除する"),
+    (
+        "train monitors retarget",
+        "対象変更",
+        "モニターが表示するトレインを変更する",
+    ),
+    (
+        "train dashboards",
+        "ダッシュボード",
+        "既存のダッシュボードを管理する",
+    ),
+    (
+        "train dashboards list",
+        "一覧",
+        "ダッシュボードを一覧表示する",
+    ),
+    (
+        "train dashboards configure",
+        "設定",
+        "ダッシュボードの表示内容を変更する",
+    ),
+    (
+        "train dashboards delete",
+        "削除",
+        "ダッシュボードを削除する",
+    ),
+    ("train theme", "テーマ", "状態の表示方法をカスタマイズする"),
+    ("train theme show", "表示", "各状態の絵文字と色を表示する"),
+    ("train theme set", "設定", "状態の絵文字や色を設定する"),
+    ("train theme reset", "リセット", "既定の絵文字と色に戻す"),
+    (
+        "train template",
+        "テンプレート",
+        "モニター、ダッシュボード、返信の文言をカスタマイズする",
+    ),
+    (
+        "train template list",
+        "一覧",
+        "すべてのテンプレートと使える変数を表示する",
+    ),
+    ("train template set", "設定", "テンプレートの文言を変更する"),
+    ("train template reset", "リセット", "既定の文言に戻す"),
+    (
+        "train language",
+        "言語",
+        "モニターとダッシュボードの言語を設定する",
+    ),
+    (
+        "train timezone",
+        "タイムゾーン",
+        "ゾーンなしで入力した時刻のタイムゾーンを設定する",
+    ),
+    (
+        ":train",
+        "トレイン",
+        "トレイン（例: Seraph EW）。ワールドと拡張の代わりに指定",
+    ),
+    (
+        "maintenance",
+        "メンテナンス",
+        "サーバーメンテナンスを予定し、発生時に全トレインをリセットする",
+    ),
+    (
+        "maintenance schedule",
+        "予定",
+        "全トレインに自動で適用するメンテナンスを予定する",
+    ),
+    (
+        "maintenance cancel",
+        "取消",
+        "まだ始まっていないメンテナンスを取り消す",
+    ),
+    (
+        "maintenance list",
+        "一覧",
+        "予定されたメンテナンスと告知チャンネルを表示する",
+    ),
+    (
+        "maintenance notices",
+        "告知",
+        "このチャンネルでメンテナンスの開始と終了を告知する",
+    ),
+    ("hunt", "モブ", "Aランクモブを個別に記録"),
+    ("hunt killed", "討伐", "モブ1体の討伐を報告"),
+    (
+        "hunt marks",
+        "モブ一覧",
+        "トレインの各モブの湧き時間帯を表示",
+    ),
+    ("srank", "sランク", "Sランクの湧き時間帯を記録"),
+    ("srank killed", "討伐", "Sランクの討伐を報告"),
+    ("srank status", "状況", "ワールドのSランク湧き時間帯を表示"),
+    ("srank set", "設定", "Sランクの湧き時間帯と湧き条件を設定"),
+    (
+        "srank create_dashboard",
+        "ダッシュボード作成",
+        "Sランクのダッシュボードを追加",
+    ),
+    (":world", "ワールド", "ワールド"),
+    (":expac", "拡張", "拡張パッケージ"),
+    (
+        ":map_link",
+        "マップ",
+        "マップまたは湧き位置を示すメッセージへのリンク",
+    ),
+    (
+        "train scout:map_link",
+        "マップ",
+        "マップまたは湧き位置を示すメッセージへのリンク（空欄で既存のマップを消去）",
+    ),
+    (":completion_time", "完了時刻", "完了した時刻（既定: 現在）"),
+    (
+        ":force_time",
+        "強制湧き時刻",
+        "強制湧きの時刻（完了時刻とは同時に指定できません）",
+    ),
+    (":sticky", "固定", "削除されたら再投稿する"),
+    (":id", "id", "一覧コマンドで表示される ID"),
+    (
+        ":data_center",
+        "データセンター",
+        "このデータセンターのみ表示する（既定: すべて）",
+    ),
+    (
+        ":overview",
+        "概要",
+        "トレインごとではなくデータセンターごとの件数を表示する",
+    ),
+    (
+        ":worlds",
+        "ワールド一覧",
+        "表示するワールド（カンマ区切り、または all）",
+    ),
+    (
+        ":expacs",
+        "拡張一覧",
+        "表示する拡張（カンマ区切り、または all）",
+    ),
+    (":transposed", "転置", "ワールドを行として表示する"),
+    (
+        ":sort_by_force",
+        "強制湧き順",
+        "強制湧きが近い順にワールドを並べる",
+    ),
+    (":compact", "コンパクト", "説明文を省き短いラベルを使う"),
+    (":status", "状態", "対象の状態"),
+    (":emoji", "絵文字", "Unicode またはサーバーの絵文字"),
+    (":colour", "色", "埋め込みの色（例: #FF8800）"),
+    (":key", "テンプレート", "対象のテンプレート"),
+    (
+        ":text",
+        "文言",
+        "新しい文言（{world} などの変数が使えます）",
+    ),
+    (":language", "言語", "モニターとダッシュボードの言語"),
+    (":zone", "ゾーン", "略称またはオフセット（例: JST、UTC+9）"),
+    (
+        "train batch done:expacs",
+        "拡張一覧",
+        "更新する拡張（カンマ区切り、または all）",
+    ),
+    (
+        "train batch start:expacs",
+        "拡張一覧",
+        "更新する拡張（カンマ区切り、または all）",
+    ),
+    (
+        "train batch reset:expacs",
+        "拡張一覧",
+        "更新する拡張（カンマ区切り、または all）",
+    ),
+    (
+        ":end_time",
+        "終了時刻",
+        "サーバーが復旧した時刻（既定: 現在）",
+    ),
+    (":start", "開始", "メンテナンスの開始時刻"),
+    (":end", "終了", "サーバーが復旧する時刻"),
+    (
+        ":enabled",
+        "有効",
+        "このチャンネルで告知するかどうか（既定: はい）",
+    ),
+    (":time", "時刻", "トレインの開始時刻（例: 21:30、in 2h）"),
+    (":conductor", "主催者", "トレインの主催者（既定: 自分）"),
+    (":notes", "メモ", "参加者へのお知らせ"),
+    (
+        ":remind",
+        "リマインド",
+        "開始の何分前にここでリマインドするか（既定: 15）",
+    ),
+    (
+        ":location",
+        "集合場所",
+        "トレインの開始地点（Discord イベントに表示）",
+    ),
+    (
+        ":repeat_weeks",
+        "繰り返し週",
+        "この週数ごとにトレインを繰り返す",
+    ),
+    (
+        ":series",
+        "シリーズ",
+        "/train schedule list で表示されるシリーズ ID",
+    ),
+    (":paused", "停止", "計画を止めるかどうか（既定: はい）"),
+    (":kind", "種類", "アラートの種類"),
+    (":role", "ロール", "通知するロール"),
+    (
+        ":minutes",
+        "分",
+        "soon アラートを強制湧きの何分前に投稿するか（既定: 30）",
+    ),
+    (":subscribed", "購読", "通知を受けるかどうか（既定: はい）"),
+    (":hours", "時間", "更新なしの時間数、0で期限なし"),
+    (":kill_time", "討伐時刻", "モブが倒された時刻（既定: 現在）"),
+    (
+        ":marks_left",
+        "残りモブ数",
+        "まだ生きているモブの数（既定: 討伐なし）",
+    ),
+    (":mark", "モブ", "Aランクモブ"),
+    ("srank killed:zone", "エリア", "Sランクのエリア"),
+    ("srank set:zone", "エリア", "Sランクのエリア"),
+    (
+        "srank set:notes",
+        "湧き条件",
+        "湧かせるために必要なこと（「-」で消去）",
+    ),
+    (":min_hours", "最短時間", "討伐後、再び湧けるまでの時間数"),
+    (
+        ":max_hours",
+        "最長時間",
+        "討伐後、確実に湧けるようになるまでの時間数",
+    ),
+];
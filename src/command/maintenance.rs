@@ -0,0 +1,163 @@
+use chrono::Utc;
+use eyre::{bail, eyre};
+use sea_orm::{ActiveModelTrait, ColumnTrait, EntityTrait, NotSet, QueryFilter, QueryOrder, Set};
+
+use super::{argument, Context};
+use crate::train::command::list_description;
+use entity::{maintenance_window, notice_channel};
+
+/// Schedule server maintenance, which resets every train when it happens.
+#[poise::command(slash_command, subcommands("schedule", "cancel", "list", "notices"))]
+pub async fn maintenance(_ctx: Context<'_>) -> eyre::Result<()> {
+    Err(eyre!("unsupported"))
+}
+
+/// Plan a maintenance window, to be applied to every train automatically
+#[poise::command(slash_command, required_permissions = "MANAGE_GUILD")]
+pub async fn schedule(
+    ctx: Context<'_>,
+    #[description = "When maintenance begins, e.g. \"2026-10-20 08:00 UTC\" or \"in 2d\""]
+    start: argument::Timestamp,
+    #[description = "When the servers come back up"] end: argument::Timestamp,
+) -> eyre::Result<()> {
+    if ctx.guild_id() != Some(ctx.data().train_guild_id) {
+        bail!("Not allowed in this guild/in DM");
+    }
+    let start = start.resolve_after(ctx, Utc::now()).await?;
+    let end = end.resolve_after(ctx, start).await?;
+    if end <= start {
+        bail!("Maintenance must end after it starts");
+    }
+    if end <= Utc::now() {
+        bail!("Maintenance has already ended; use `/train maintenance end` instead");
+    }
+    let window = maintenance_window::ActiveModel {
+        id: NotSet,
+        start_time: Set(start),
+        end_time: Set(end),
+        started: Set(false),
+        ended: Set(false),
+    }
+    .insert(&ctx.data().db)
+    .await?;
+    ctx.say(format!(
+        "Maintenance `#{}` scheduled from <t:{}:f> to <t:{}:f>.",
+        window.id,
+        start.timestamp(),
+        end.timestamp()
+    ))
+    .await?;
+    Ok(())
+}
+
+/// Drop a maintenance window that has not started yet
+#[poise::command(slash_command, required_permissions = "MANAGE_GUILD")]
+pub async fn cancel(
+    ctx: Context<'_>,
+    #[description = "Maintenance ID, as shown by `/maintenance list`"] id: i64,
+) -> eyre::Result<()> {
+    if ctx.guild_id() != Some(ctx.data().train_guild_id) {
+        bail!("Not allowed in this guild/in DM");
+    }
+    let db = &ctx.data().db;
+    let Some(window) = maintenance_window::Entity::find_by_id(id).one(db).await? else {
+        bail!("No maintenance with ID {}", id);
+    };
+    if window.started {
+        bail!(
+            "Maintenance `#{}` has already started; use `/train maintenance end` to end it early",
+            id
+        );
+    }
+    maintenance_window::Entity::delete_by_id(id)
+        .exec(db)
+        .await?;
+    ctx.say(format!("Maintenance `#{}` cancelled.", id)).await?;
+    Ok(())
+}
+
+/// List upcoming maintenance and the channels it is announced in
+#[poise::command(slash_command, ephemeral)]
+pub async fn list(ctx: Context<'_>) -> eyre::Result<()> {
+    if ctx.guild_id() != Some(ctx.data().train_guild_id) {
+        bail!("Not allowed in this guild/in DM");
+    }
+    let db = &ctx.data().db;
+    let windows = maintenance_window::Entity::find()
+        .filter(maintenance_window::Column::Ended.eq(false))
+        .order_by_asc(maintenance_window::Column::StartTime)
+        .all(db)
+        .await?;
+    let channels = notice_channel::Entity::find().all(db).await?;
+
+    let lines = windows
+        .into_iter()
+        .map(|window| {
+            format!(
+                "`#{}` <t:{}:f> to <t:{}:f>{}",
+                window.id,
+                window.start_time.timestamp(),
+                window.end_time.timestamp(),
+                if window.started { " (ongoing)" } else { "" },
+            )
+        })
+        .collect();
+    let channels = if channels.is_empty() {
+        "Not announced anywhere.".to_owned()
+    } else {
+        let mentions: Vec<_> = channels
+            .iter()
+            .map(|c| format!("<#{}>", c.channel_id))
+            .collect();
+        format!("Announced in {}.", mentions.join(", "))
+    };
+    ctx.send(|m| {
+        m.embed(|e| {
+            e.title("Scheduled Maintenance")
+                .description(list_description(lines, "No maintenance scheduled."))
+                .footer(|f| f.text(channels))
+        })
+    })
+    .await?;
+    Ok(())
+}
+
+/// Announce the start and end of scheduled maintenance in this channel
+#[poise::command(slash_command, ephemeral, required_permissions = "MANAGE_GUILD")]
+pub async fn notices(
+    ctx: Context<'_>,
+    #[description = "Whether to announce maintenance here (default: true)"] enabled: Option<bool>,
+) -> eyre::Result<()> {
+    if ctx.guild_id() != Some(ctx.data().train_guild_id) {
+        bail!("Not allowed in this guild/in DM");
+    }
+    let db = &ctx.data().db;
+    let channel_id = ctx.channel_id().0 as i64;
+    let existing = notice_channel::Entity::find()
+        .filter(notice_channel::Column::ChannelId.eq(channel_id))
+        .one(db)
+        .await?;
+    match (existing, enabled.unwrap_or(true)) {
+        (None, true) => {
+            notice_channel::ActiveModel {
+                id: NotSet,
+                channel_id: Set(channel_id),
+            }
+            .insert(db)
+            .await?;
+        }
+        (Some(existing), false) => {
+            notice_channel::Entity::delete_by_id(existing.id)
+                .exec(db)
+                .await?;
+        }
+        _ => {}
+    }
+    ctx.say(if enabled.unwrap_or(true) {
+        "Maintenance will be announced in this channel."
+    } else {
+        "Maintenance will no longer be announced in this channel."
+    })
+    .await?;
+    Ok(())
+}
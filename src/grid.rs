@@ -0,0 +1,336 @@
+use entity::locale::{Locale, Text};
+use poise::serenity_prelude::{Colour, CreateEmbed};
+
+// Discord's embed limits, see https://discord.com/developers/docs/resources/channel#embed-object-embed-limits
+const MAX_FIELDS: usize = 25;
+const MAX_FIELD_VALUE: usize = 1024;
+const MAX_EMBEDS: usize = 10;
+const MAX_TOTAL_CHARS: usize = 6000;
+
+/// A table shown as columns of inline embed fields, with the row labels in
+/// the first field of every embed.
+pub struct Grid {
+    pub title: String,
+    /// Name of the field holding the row labels.
+    pub corner: String,
+    pub row_labels: Vec<String>,
+    /// Column names, each with one cell per row.
+    pub columns: Vec<(String, Vec<String>)>,
+    /// Colour of every embed.
+    pub colour: Option<Colour>,
+    /// Titles and descriptions of embeds shown after the table. Room is kept
+    /// for them, so that the table is cut short rather than these.
+    pub sections: Vec<(String, String)>,
+    /// Language of the notes shown when the table is empty or cut short.
+    pub locale: Locale,
+}
+
+impl Grid {
+    /// Lays the grid out over as many embeds as Discord's limits require.
+    ///
+    /// Rows are split first, so that every field value fits, and then columns,
+    /// so that every embed has few enough fields. Whatever does not fit in a
+    /// single message is left out with a note, rather than failing the edit.
+    pub fn into_embeds(self) -> Vec<CreateEmbed> {
+        let mut embeds = self.layout();
+        if let Some(colour) = self.colour {
+            for embed in &mut embeds {
+                embed.colour(colour);
+            }
+        }
+        embeds
+    }
+
+    fn layout(&self) -> Vec<CreateEmbed> {
+        let mut embeds = self.layout_table();
+        for (title, description) in &self.sections {
+            let mut embed = CreateEmbed::default();
+            embed.title(title).description(description);
+            embeds.push(embed);
+        }
+        embeds
+    }
+
+    fn layout_table(&self) -> Vec<CreateEmbed> {
+        let mut embed = CreateEmbed::default();
+        embed.title(&self.title);
+        if self.row_labels.is_empty() || self.columns.is_empty() {
+            embed.description(self.locale.text(Text::GridEmpty));
+            return vec![embed];
+        }
+        let max_embeds = MAX_EMBEDS - self.sections.len();
+        let reserved: usize = self
+            .sections
+            .iter()
+            .map(|(title, description)| title.chars().count() + description.chars().count())
+            .sum();
+
+        let labels: Vec<_> = self.row_labels.iter().map(|l| truncate(l)).collect();
+        let columns: Vec<(&str, Vec<&str>)> = self
+            .columns
+            .iter()
+            .map(|(name, cells)| (name.as_str(), cells.iter().map(|c| truncate(c)).collect()))
+            .collect();
+
+        let overflow = self.locale.text(Text::GridOverflow);
+        let mut embeds = Vec::new();
+        let mut chars = self.title.chars().count();
+        let mut complete = true;
+        'outer: for rows in row_chunks(&labels, &columns) {
+            for chunk in columns.chunks(MAX_FIELDS - 1) {
+                let mut fields = vec![(self.corner.as_str(), join(&labels[rows.clone()]))];
+                fields.extend(
+                    chunk
+                        .iter()
+                        .map(|(name, cells)| (*name, join(&cells[rows.clone()]))),
+                );
+                let size: usize = fields
+                    .iter()
+                    .map(|(name, value)| name.chars().count() + value.chars().count())
+                    .sum();
+                if embeds.len() == max_embeds
+                    || chars + size + overflow.chars().count() + reserved > MAX_TOTAL_CHARS
+                {
+                    complete = false;
+                    break 'outer;
+                }
+                chars += size;
+                embed.fields(fields.into_iter().map(|(name, value)| (name, value, true)));
+                embeds.push(std::mem::take(&mut embed));
+            }
+        }
+        if !complete {
+            match embeds.last_mut() {
+                Some(last) => {
+                    last.description(overflow);
+                }
+                None => {
+                    embed.description(overflow);
+                    embeds.push(embed);
+                }
+            }
+        }
+        embeds
+    }
+}
+
+/// Cuts a cell down so that it fits in a field value by itself.
+fn truncate(cell: &str) -> &str {
+    match cell.char_indices().nth(MAX_FIELD_VALUE - 1) {
+        Some((end, _)) => &cell[..end],
+        None => cell,
+    }
+}
+
+fn join(cells: &[&str]) -> String {
+    let value = cells.join("\n");
+    if value.is_empty() {
+        // Discord rejects empty field values.
+        "\u{200b}".to_owned()
+    } else {
+        value
+    }
+}
+
+/// Splits the rows into ranges whose cells fit into a single field value in
+/// every column.
+fn row_chunks(labels: &[&str], columns: &[(&str, Vec<&str>)]) -> Vec<std::ops::Range<usize>> {
+    let mut chunks = Vec::new();
+    let mut start = 0;
+    let mut lengths = vec![0; columns.len() + 1];
+    for (row, label) in labels.iter().enumerate() {
+        // Every cell takes up its own length plus a newline.
+        let widths: Vec<_> = std::iter::once(label)
+            .chain(columns.iter().map(|(_, cells)| &cells[row]))
+            .map(|cell| cell.chars().count() + 1)
+            .collect();
+        let fits = widths
+            .iter()
+            .zip(&lengths)
+            .all(|(width, len)| len + width <= MAX_FIELD_VALUE);
+        if !fits {
+            chunks.push(start..row);
+            start = row;
+            lengths.iter_mut().for_each(|len| *len = 0);
+        }
+        for (width, len) in widths.into_iter().zip(&mut lengths) {
+            *len += width;
+        }
+    }
+    chunks.push(start..labels.len());
+    chunks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid(rows: usize, columns: usize, cell: &str) -> Grid {
+        Grid {
+            title: "Title".to_owned(),
+            corner: "Corner".to_owned(),
+            row_labels: (0..rows).map(|r| format!("r{}", r)).collect(),
+            columns: (0..columns)
+                .map(|c| (format!("c{}", c), vec![cell.to_owned(); rows]))
+                .collect(),
+            colour: None,
+            sections: Vec::new(),
+            locale: Locale::English,
+        }
+    }
+
+    fn fields(embed: &CreateEmbed) -> Vec<(String, String)> {
+        let Some(fields) = embed.0.get("fields").and_then(|f| f.as_array()) else {
+            return Vec::new();
+        };
+        fields
+            .iter()
+            .map(|f| {
+                (
+                    f["name"].as_str().unwrap().to_owned(),
+                    f["value"].as_str().unwrap().to_owned(),
+                )
+            })
+            .collect()
+    }
+
+    fn description(embed: &CreateEmbed) -> Option<&str> {
+        embed.0.get("description").and_then(|d| d.as_str())
+    }
+
+    /// Characters that count towards Discord's limit on the whole message.
+    fn total_chars(embeds: &[CreateEmbed]) -> usize {
+        embeds
+            .iter()
+            .map(|embed| {
+                let text = |key| {
+                    embed
+                        .0
+                        .get(key)
+                        .and_then(|v: &serenity::json::Value| v.as_str())
+                        .map_or(0, |s| s.chars().count())
+                };
+                let fields: usize = fields(embed)
+                    .iter()
+                    .map(|(name, value)| name.chars().count() + value.chars().count())
+                    .sum();
+                text("title") + text("description") + fields
+            })
+            .sum()
+    }
+
+    fn overflow() -> &'static str {
+        Locale::English.text(Text::GridOverflow)
+    }
+
+    #[test]
+    fn empty_grids_show_a_note() {
+        let embeds = grid(0, 3, "x").layout_table();
+        assert_eq!(embeds.len(), 1);
+        assert_eq!(
+            description(&embeds[0]),
+            Some(Locale::English.text(Text::GridEmpty))
+        );
+        let embeds = grid(3, 0, "x").layout_table();
+        assert_eq!(embeds.len(), 1);
+        assert_eq!(
+            description(&embeds[0]),
+            Some(Locale::English.text(Text::GridEmpty))
+        );
+    }
+
+    #[test]
+    fn rows_are_split_at_the_field_value_limit() {
+        let labels = ["a", "b", "c"];
+        // Every cell takes its length plus a newline, so two of these fill a field exactly.
+        let half = "x".repeat(MAX_FIELD_VALUE / 2 - 1);
+        let columns = vec![("c", vec![half.as_str(); 3])];
+        assert_eq!(row_chunks(&labels, &columns), vec![0..2, 2..3]);
+
+        let full = "x".repeat(MAX_FIELD_VALUE - 1);
+        let columns = vec![("c", vec!["x", full.as_str(), "x"])];
+        assert_eq!(row_chunks(&labels, &columns), vec![0..1, 1..2, 2..3]);
+
+        let columns = vec![("c", vec!["x"; 3])];
+        assert_eq!(row_chunks(&labels, &columns), vec![0..3]);
+    }
+
+    #[test]
+    fn long_cells_are_truncated_to_fit_a_field() {
+        let embeds = grid(1, 1, &"x".repeat(2 * MAX_FIELD_VALUE)).layout_table();
+        let fields = fields(&embeds[0]);
+        assert_eq!(fields[1].1.chars().count(), MAX_FIELD_VALUE - 1);
+        assert!(fields
+            .iter()
+            .all(|(_, value)| value.chars().count() <= MAX_FIELD_VALUE));
+    }
+
+    #[test]
+    fn columns_are_split_at_the_field_limit() {
+        let embeds = grid(1, MAX_FIELDS - 1, "x").layout_table();
+        assert_eq!(embeds.len(), 1);
+        assert_eq!(fields(&embeds[0]).len(), MAX_FIELDS);
+
+        let embeds = grid(1, MAX_FIELDS, "x").layout_table();
+        assert_eq!(embeds.len(), 2);
+        assert_eq!(fields(&embeds[0]).len(), MAX_FIELDS);
+        // Every embed repeats the row labels.
+        assert_eq!(
+            fields(&embeds[1]),
+            vec![
+                ("Corner".to_owned(), "r0".to_owned()),
+                (format!("c{}", MAX_FIELDS - 1), "x".to_owned())
+            ]
+        );
+        assert!(embeds.iter().all(|e| description(e).is_none()));
+    }
+
+    #[test]
+    fn grids_are_cut_short_at_the_embed_limit() {
+        let embeds = grid(1, MAX_EMBEDS * (MAX_FIELDS - 1), "x").layout_table();
+        assert_eq!(embeds.len(), MAX_EMBEDS);
+        assert!(embeds.iter().all(|e| description(e).is_none()));
+
+        let embeds = grid(1, MAX_EMBEDS * (MAX_FIELDS - 1) + 1, "x").layout_table();
+        assert_eq!(embeds.len(), MAX_EMBEDS);
+        assert_eq!(description(embeds.last().unwrap()), Some(overflow()));
+    }
+
+    #[test]
+    fn sections_take_up_embeds() {
+        let mut grid = grid(1, MAX_EMBEDS * (MAX_FIELDS - 1), "x");
+        grid.sections = vec![
+            ("One".to_owned(), "First".to_owned()),
+            ("Two".to_owned(), "Second".to_owned()),
+        ];
+        let embeds = grid.layout();
+        assert_eq!(embeds.len(), MAX_EMBEDS);
+        assert_eq!(description(&embeds[MAX_EMBEDS - 3]), Some(overflow()));
+        assert_eq!(description(&embeds[MAX_EMBEDS - 1]), Some("Second"));
+    }
+
+    #[test]
+    fn grids_are_cut_short_at_the_character_limit() {
+        // Each row needs its own embed, and six of them don't fit in one message.
+        let cell = "x".repeat(MAX_FIELD_VALUE - 2);
+        let embeds = grid(6, 1, &cell).layout_table();
+        assert!(embeds.len() < 6);
+        assert_eq!(description(embeds.last().unwrap()), Some(overflow()));
+        assert!(total_chars(&embeds) <= MAX_TOTAL_CHARS);
+
+        let embeds = grid(5, 1, &cell).layout_table();
+        assert_eq!(embeds.len(), 5);
+        assert!(embeds.iter().all(|e| description(e).is_none()));
+        assert!(total_chars(&embeds) <= MAX_TOTAL_CHARS);
+    }
+
+    #[test]
+    fn the_overflow_note_gets_its_own_embed_if_nothing_fits() {
+        let mut grid = grid(1, 1, &"x".repeat(MAX_FIELD_VALUE));
+        grid.title = "x".repeat(MAX_TOTAL_CHARS - MAX_FIELD_VALUE);
+        let embeds = grid.layout_table();
+        assert_eq!(embeds.len(), 1);
+        assert!(fields(&embeds[0]).is_empty());
+        assert_eq!(description(&embeds[0]), Some(overflow()));
+    }
+}
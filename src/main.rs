@@ -1,4 +1,9 @@
 pub mod command;
+pub mod grid;
+pub mod reconcile;
+pub mod refresh;
+pub mod schedule;
+pub mod srank;
 pub mod train;
 
 use clap::Parser;
@@ -31,10 +36,7 @@ async fn main() -> eyre::Result<()> {
     migration::Migrator::up(&db, None).await?;
 
     // Build our client.
-    let data = command::Data {
-        db,
-        train_guild_id: GuildId(args.train_guild_id),
-    };
+    let train_guild_id = GuildId(args.train_guild_id);
     let framework = poise::Framework::builder()
         .options(poise::FrameworkOptions {
             commands: command::all(),
@@ -52,6 +54,14 @@ async fn main() -> eyre::Result<()> {
         .user_data_setup(move |ctx, _r, framework| {
             Box::pin(async move {
                 eprintln!("Initializing...");
+                let refresh = refresh::Queue::new(ctx.http.clone(), db.clone(), train_guild_id);
+                reconcile::spawn(ctx.http.clone(), db.clone(), refresh.clone());
+                schedule::spawn(ctx.http.clone(), db.clone(), refresh.clone(), train_guild_id);
+                let data = command::Data {
+                    refresh,
+                    db,
+                    train_guild_id,
+                };
                 let channel = UserId(args.owner_id).create_dm_channel(ctx).await?;
                 channel
                     .send_message(ctx, |m| m.content("Greetings, owner! I wish only to hear your words, share your feelings, know your thoughts."))
@@ -59,7 +69,7 @@ async fn main() -> eyre::Result<()> {
                 data.train_guild_id
                     .set_application_commands(&ctx.http, |b| {
                         *b = poise::builtins::create_application_commands(
-                            &*framework.options().commands,
+                            &framework.options().commands,
                         );
                         b
                     })
@@ -0,0 +1,193 @@
+use poise::serenity_prelude::{Channel, ChannelId, Http, Permissions, UserId};
+use sea_orm::{ActiveModelTrait, DbConn, EntityTrait, PrimaryKeyTrait};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::refresh::{self, Queue, Tracked};
+use entity::{dashboard, monitor, s_rank_dashboard};
+
+/// How often tracked posts are checked in the background.
+const INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// Outcome of a reconciliation pass.
+#[derive(Default)]
+pub struct Summary {
+    pub checked: usize,
+    pub pruned: usize,
+    pub recreated: usize,
+    /// Posts that still exist but that we can no longer edit, which are also
+    /// counted as pruned or recreated.
+    pub uneditable: usize,
+    pub failed: usize,
+}
+
+impl std::fmt::Display for Summary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "checked {} posts ({} no longer editable), pruned {}, recreated {}, unable to check {}",
+            self.checked, self.uneditable, self.pruned, self.recreated, self.failed
+        )
+    }
+}
+
+enum Check {
+    Alive,
+    Gone,
+    /// The post exists, but it isn't ours or we may no longer post in its channel.
+    Uneditable,
+}
+
+/// What a reconciliation pass has learned about our own access.
+struct Access<'a> {
+    http: &'a Http,
+    user_id: UserId,
+    /// Whether we may post embeds, per channel checked so far.
+    channels: HashMap<u64, bool>,
+}
+
+impl<'a> Access<'a> {
+    async fn new(http: &'a Http) -> serenity::Result<Access<'a>> {
+        Ok(Access {
+            http,
+            user_id: http.get_current_user().await?.id,
+            channels: HashMap::new(),
+        })
+    }
+
+    /// Whether we may still send, and so edit, embeds in the channel.
+    async fn can_post(&mut self, channel_id: u64) -> serenity::Result<bool> {
+        if let Some(&can_post) = self.channels.get(&channel_id) {
+            return Ok(can_post);
+        }
+        let can_post = match self.http.get_channel(channel_id).await? {
+            Channel::Guild(channel) => {
+                let guild = self.http.get_guild(channel.guild_id.0).await?;
+                let member = self
+                    .http
+                    .get_member(channel.guild_id.0, self.user_id.0)
+                    .await?;
+                guild.user_permissions_in(&channel, &member)?.contains(
+                    Permissions::VIEW_CHANNEL
+                        | Permissions::SEND_MESSAGES
+                        | Permissions::EMBED_LINKS,
+                )
+            }
+            // Guild permissions don't apply to DMs.
+            _ => true,
+        };
+        self.channels.insert(channel_id, can_post);
+        Ok(can_post)
+    }
+}
+
+/// Fetches a tracked message to find out whether it still exists and we can
+/// still edit it.
+async fn check(
+    access: &mut Access<'_>,
+    channel_id: i64,
+    message_id: i64,
+) -> serenity::Result<Check> {
+    let message = match ChannelId(channel_id as u64)
+        .message(access.http, message_id as u64)
+        .await
+    {
+        Ok(message) => message,
+        Err(ref e) if refresh::is_gone(e) => return Ok(Check::Gone),
+        Err(e) => return Err(e),
+    };
+    if message.author.id != access.user_id || !access.can_post(channel_id as u64).await? {
+        return Ok(Check::Uneditable);
+    }
+    Ok(Check::Alive)
+}
+
+/// Forces a sticky post to be re-rendered, which reposts it if possible, and
+/// records what happened in `summary`.
+async fn recreate<T: Tracked>(db: &DbConn, queue: &Queue, row: T, summary: &mut Summary)
+where
+    T::Entity: EntityTrait<Model = T>,
+    <<T::Entity as EntityTrait>::PrimaryKey as PrimaryKeyTrait>::ValueType: From<i64>,
+{
+    let (id, target) = (row.id(), row.target());
+    let mut reset = row.into_active_model();
+    T::set_content_hash(&mut reset, None);
+    let refreshed = reset.update(db).await.is_ok() && queue.refresh([target]).await;
+    let exists = T::Entity::find_by_id(id.into()).one(db).await;
+    match (refreshed, exists.map(|row| row.is_some())) {
+        (true, Ok(true)) => summary.recreated += 1,
+        (true, Ok(false)) => summary.pruned += 1,
+        _ => {
+            eprintln!("Warning: Unable to recreate {}", target);
+            summary.failed += 1;
+        }
+    }
+}
+
+/// Checks every post tracked in the table of `T`, as described for `reconcile`.
+async fn reconcile_table<T: Tracked>(
+    access: &mut Access<'_>,
+    db: &DbConn,
+    queue: &Queue,
+    summary: &mut Summary,
+) -> eyre::Result<()>
+where
+    T::Entity: EntityTrait<Model = T>,
+    <<T::Entity as EntityTrait>::PrimaryKey as PrimaryKeyTrait>::ValueType: From<i64>,
+{
+    for row in T::Entity::find().all(db).await? {
+        summary.checked += 1;
+        let check = check(access, row.channel_id(), row.message_id()).await;
+        if let Ok(Check::Uneditable) = check {
+            summary.uneditable += 1;
+        }
+        match check {
+            Ok(Check::Alive) => {}
+            Ok(Check::Gone | Check::Uneditable) if row.sticky() => {
+                recreate(db, queue, row, summary).await
+            }
+            Ok(Check::Gone | Check::Uneditable) => match row.delete(db).await {
+                Ok(_) => summary.pruned += 1,
+                Err(e) => {
+                    eprintln!("Warning: Unable to delete stale message from our DB: {}", e);
+                    summary.failed += 1;
+                }
+            },
+            Err(e) => {
+                eprintln!("Warning: Unable to check {}: {}", row.target(), e);
+                summary.failed += 1;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Checks every tracked monitor and dashboard post, S-rank ones included. Rows
+/// of posts that have been deleted or that we can no longer access or edit
+/// are removed, unless they are sticky and can be reposted.
+///
+/// Only failing to look ourselves up or to list the rows is an error; problems
+/// with individual posts are printed to stderr and counted in the summary.
+pub async fn reconcile(http: &Http, db: &DbConn, queue: &Queue) -> eyre::Result<Summary> {
+    let mut summary = Summary::default();
+    let mut access = Access::new(http).await?;
+    reconcile_table::<monitor::Model>(&mut access, db, queue, &mut summary).await?;
+    reconcile_table::<dashboard::Model>(&mut access, db, queue, &mut summary).await?;
+    reconcile_table::<s_rank_dashboard::Model>(&mut access, db, queue, &mut summary).await?;
+    Ok(summary)
+}
+
+/// Runs `reconcile` immediately and then periodically in the background.
+pub fn spawn(http: Arc<Http>, db: DbConn, queue: Queue) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(INTERVAL);
+        loop {
+            interval.tick().await;
+            match reconcile(&http, &db, &queue).await {
+                Ok(summary) => eprintln!("Reconciled tracked posts: {}", summary),
+                Err(e) => eprintln!("Warning: Unable to reconcile tracked posts: {}", e),
+            }
+        }
+    });
+}
@@ -0,0 +1,452 @@
+use poise::serenity_prelude::{
+    ChannelId, CreateComponents, CreateEmbed, GuildId, Http, MessageId, StatusCode,
+};
+use sea_orm::{
+    ActiveModelBehavior, ActiveModelTrait, DbConn, EntityTrait, IntoActiveModel, ModelTrait, Set,
+};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{mpsc, oneshot, Semaphore};
+use tokio::time::Instant;
+
+use entity::{dashboard, monitor, s_rank_dashboard};
+
+/// How long to wait after the first request for a message before editing it,
+/// so that several updates in quick succession only produce a single edit.
+const DEBOUNCE: Duration = Duration::from_secs(2);
+/// Maximum number of message edits in flight at once.
+const CONCURRENCY: usize = 4;
+/// Number of attempts made before giving up on a message.
+const MAX_ATTEMPTS: u32 = 5;
+/// Delay before the first retry; doubled for every subsequent retry.
+const INITIAL_BACKOFF: Duration = Duration::from_secs(1);
+
+/// A tracked message that can be refreshed.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum Target {
+    Monitor(i64),
+    Dashboard(i64),
+    SRankDashboard(i64),
+}
+
+impl std::fmt::Display for Target {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Target::Monitor(id) => write!(f, "monitor {}", id),
+            Target::Dashboard(id) => write!(f, "dashboard {}", id),
+            Target::SRankDashboard(id) => write!(f, "S-rank dashboard {}", id),
+        }
+    }
+}
+
+struct Request {
+    target: Target,
+    done: oneshot::Sender<bool>,
+}
+
+/// Handle to the background task that edits monitor and dashboard posts.
+///
+/// Requests for the same message are coalesced, and the rendered content is
+/// always built from the database at the time of the edit, so a coalesced
+/// request never shows stale data.
+#[derive(Clone)]
+pub struct Queue {
+    tx: mpsc::UnboundedSender<Request>,
+}
+
+impl Queue {
+    /// Spawns the background worker. Must be called from within a Tokio runtime.
+    ///
+    /// Posts are rendered with the settings of `guild_id`, the only guild in
+    /// which they can be created.
+    pub fn new(http: Arc<Http>, db: DbConn, guild_id: GuildId) -> Self {
+        let (tx, rx) = mpsc::unbounded_channel();
+        tokio::spawn(Worker::new(http, db, guild_id).run(rx));
+        Queue { tx }
+    }
+
+    /// Queues a refresh of all `targets` and waits until every one of them has
+    /// either been refreshed or has been given up on.
+    ///
+    /// Prints errors to stderr and reports only success/failure.
+    pub async fn refresh(&self, targets: impl IntoIterator<Item = Target>) -> bool {
+        let mut results = Vec::new();
+        for target in targets {
+            let (done, result) = oneshot::channel();
+            if self.tx.send(Request { target, done }).is_err() {
+                eprintln!("Warning: Refresh queue has shut down");
+                return false;
+            }
+            results.push(result);
+        }
+        let mut success = true;
+        for result in results {
+            success &= result.await.unwrap_or(false);
+        }
+        success
+    }
+}
+
+struct Queued {
+    due: Instant,
+    waiters: Vec<oneshot::Sender<bool>>,
+}
+
+struct Worker {
+    http: Arc<Http>,
+    db: DbConn,
+    guild_id: GuildId,
+    permits: Arc<Semaphore>,
+    pending: HashMap<Target, Queued>,
+    running: HashSet<Target>,
+}
+
+impl Worker {
+    fn new(http: Arc<Http>, db: DbConn, guild_id: GuildId) -> Self {
+        Worker {
+            http,
+            db,
+            guild_id,
+            permits: Arc::new(Semaphore::new(CONCURRENCY)),
+            pending: HashMap::new(),
+            running: HashSet::new(),
+        }
+    }
+
+    async fn run(mut self, mut rx: mpsc::UnboundedReceiver<Request>) {
+        let (finished_tx, mut finished_rx) = mpsc::unbounded_channel();
+        loop {
+            // A target that is already being edited has to wait for that edit
+            // to finish, otherwise two edits of one message could race.
+            let next_due = self
+                .pending
+                .iter()
+                .filter(|(target, _)| !self.running.contains(target))
+                .map(|(_, pending)| pending.due)
+                .min();
+            tokio::select! {
+                request = rx.recv() => {
+                    let Some(Request { target, done }) = request else {
+                        break;
+                    };
+                    self.pending
+                        .entry(target)
+                        .or_insert_with(|| Queued {
+                            due: Instant::now() + DEBOUNCE,
+                            waiters: Vec::new(),
+                        })
+                        .waiters
+                        .push(done);
+                }
+                Some(target) = finished_rx.recv() => {
+                    self.running.remove(&target);
+                }
+                _ = tokio::time::sleep_until(next_due.unwrap_or_else(Instant::now)), if next_due.is_some() => {
+                    self.dispatch(&finished_tx);
+                }
+            }
+        }
+    }
+
+    /// Starts every pending refresh that is due and not already running.
+    fn dispatch(&mut self, finished: &mpsc::UnboundedSender<Target>) {
+        let now = Instant::now();
+        let due: Vec<_> = self
+            .pending
+            .iter()
+            .filter(|(target, pending)| pending.due <= now && !self.running.contains(target))
+            .map(|(&target, _)| target)
+            .collect();
+        for target in due {
+            let Queued { waiters, .. } = self.pending.remove(&target).unwrap();
+            self.running.insert(target);
+            let http = self.http.clone();
+            let db = self.db.clone();
+            let guild_id = self.guild_id;
+            let permits = self.permits.clone();
+            let finished = finished.clone();
+            let refresh = tokio::spawn(async move {
+                match permits.acquire().await {
+                    Ok(_permit) => refresh_with_retry(&http, &db, guild_id, target).await,
+                    Err(_) => false,
+                }
+            });
+            // Even if the refresh panics, its waiters get an answer and the
+            // target is released for later refreshes.
+            tokio::spawn(async move {
+                let success = refresh.await.unwrap_or_else(|e| {
+                    eprintln!("Warning: Unable to update {}: {}", target, e);
+                    false
+                });
+                for waiter in waiters {
+                    let _ = waiter.send(success);
+                }
+                let _ = finished.send(target);
+            });
+        }
+    }
+}
+
+async fn refresh_with_retry(http: &Http, db: &DbConn, guild_id: GuildId, target: Target) -> bool {
+    let mut backoff = INITIAL_BACKOFF;
+    let mut attempt = 1;
+    loop {
+        let result = match target {
+            Target::Monitor(id) => crate::train::refresh_monitor(http, db, guild_id, id).await,
+            Target::Dashboard(id) => crate::train::refresh_dashboard(http, db, guild_id, id).await,
+            Target::SRankDashboard(id) => {
+                crate::srank::refresh_dashboard(http, db, guild_id, id).await
+            }
+        };
+        match result {
+            Ok(()) => return true,
+            Err(e) if attempt < MAX_ATTEMPTS && is_retryable(&e) => {
+                eprintln!(
+                    "Warning: Unable to update {} (attempt {}), retrying in {:?}: {}",
+                    target, attempt, backoff, e
+                );
+                tokio::time::sleep(backoff).await;
+                backoff *= 2;
+                attempt += 1;
+            }
+            Err(e) => {
+                eprintln!("Warning: Unable to update {}: {}", target, e);
+                return false;
+            }
+        }
+    }
+}
+
+/// Everything shown in a tracked message.
+#[derive(Clone)]
+pub struct Post {
+    pub content: String,
+    pub embeds: Vec<CreateEmbed>,
+    pub components: CreateComponents,
+}
+
+impl Post {
+    /// Hash of the post, stored alongside a tracked message so that edits which
+    /// would not change anything can be skipped.
+    ///
+    /// `DefaultHasher` is not guaranteed to be stable across Rust releases; the
+    /// worst that can happen after an upgrade is a single redundant edit.
+    pub fn hash(&self) -> i64 {
+        let mut hasher = DefaultHasher::new();
+        self.content.hash(&mut hasher);
+        for embed in &self.embeds {
+            // Builders are backed by `HashMap`s, so fix the key order first.
+            for (key, value) in embed.0.iter().collect::<BTreeMap<_, _>>() {
+                key.hash(&mut hasher);
+                value.to_string().hash(&mut hasher);
+            }
+        }
+        for row in &self.components.0 {
+            row.to_string().hash(&mut hasher);
+        }
+        hasher.finish() as i64
+    }
+}
+
+enum Edit {
+    Edited,
+    /// The message was gone, so a new one was posted in its place.
+    Reposted(MessageId),
+    /// The message has been deleted or we no longer have permission to edit it.
+    Gone,
+}
+
+/// Replaces the contents of a tracked message with `post`.
+///
+/// If the message is gone and `sticky` is set, a new message is posted in the
+/// same channel instead, provided we can still write to it.
+async fn edit(
+    http: &Http,
+    channel_id: i64,
+    message_id: i64,
+    sticky: bool,
+    post: Post,
+) -> serenity::Result<Edit> {
+    let channel_id = ChannelId(channel_id as u64);
+    let edited = post.clone();
+    let result = channel_id
+        .edit_message(http, message_id as u64, |m| {
+            m.content(edited.content)
+                .set_embeds(edited.embeds)
+                .set_components(edited.components)
+        })
+        .await;
+    match result {
+        Ok(_) => Ok(Edit::Edited),
+        Err(ref e) if is_gone(e) && sticky => {
+            let result = channel_id
+                .send_message(http, |m| {
+                    m.content(post.content)
+                        .set_embeds(post.embeds)
+                        .set_components(post.components)
+                })
+                .await;
+            match result {
+                Ok(msg) => Ok(Edit::Reposted(msg.id)),
+                Err(ref e) if is_gone(e) => Ok(Edit::Gone),
+                Err(e) => Err(e),
+            }
+        }
+        Err(ref e) if is_gone(e) => Ok(Edit::Gone),
+        Err(e) => Err(e),
+    }
+}
+
+/// A row in our DB that tracks a posted message.
+pub trait Tracked: ModelTrait + IntoActiveModel<Self::Active> + Sized + Send {
+    type Active: ActiveModelTrait<Entity = Self::Entity> + ActiveModelBehavior + Send;
+
+    fn id(&self) -> i64;
+    fn target(&self) -> Target;
+    fn channel_id(&self) -> i64;
+    fn message_id(&self) -> i64;
+    fn sticky(&self) -> bool;
+    fn set_message_id(active: &mut Self::Active, message_id: i64);
+    fn set_content_hash(active: &mut Self::Active, hash: Option<i64>);
+}
+
+impl Tracked for monitor::Model {
+    type Active = monitor::ActiveModel;
+
+    fn id(&self) -> i64 {
+        self.id
+    }
+    fn target(&self) -> Target {
+        Target::Monitor(self.id)
+    }
+    fn channel_id(&self) -> i64 {
+        self.channel_id
+    }
+    fn message_id(&self) -> i64 {
+        self.message_id
+    }
+    fn sticky(&self) -> bool {
+        self.sticky
+    }
+    fn set_message_id(active: &mut Self::Active, message_id: i64) {
+        active.message_id = Set(message_id);
+    }
+    fn set_content_hash(active: &mut Self::Active, hash: Option<i64>) {
+        active.content_hash = Set(hash);
+    }
+}
+
+impl Tracked for dashboard::Model {
+    type Active = dashboard::ActiveModel;
+
+    fn id(&self) -> i64 {
+        self.id
+    }
+    fn target(&self) -> Target {
+        Target::Dashboard(self.id)
+    }
+    fn channel_id(&self) -> i64 {
+        self.channel_id
+    }
+    fn message_id(&self) -> i64 {
+        self.message_id
+    }
+    fn sticky(&self) -> bool {
+        self.sticky
+    }
+    fn set_message_id(active: &mut Self::Active, message_id: i64) {
+        active.message_id = Set(message_id);
+    }
+    fn set_content_hash(active: &mut Self::Active, hash: Option<i64>) {
+        active.content_hash = Set(hash);
+    }
+}
+
+impl Tracked for s_rank_dashboard::Model {
+    type Active = s_rank_dashboard::ActiveModel;
+
+    fn id(&self) -> i64 {
+        self.id
+    }
+    fn target(&self) -> Target {
+        Target::SRankDashboard(self.id)
+    }
+    fn channel_id(&self) -> i64 {
+        self.channel_id
+    }
+    fn message_id(&self) -> i64 {
+        self.message_id
+    }
+    fn sticky(&self) -> bool {
+        self.sticky
+    }
+    fn set_message_id(active: &mut Self::Active, message_id: i64) {
+        active.message_id = Set(message_id);
+    }
+    fn set_content_hash(active: &mut Self::Active, hash: Option<i64>) {
+        active.content_hash = Set(hash);
+    }
+}
+
+/// Replaces the contents of the message tracked by `row` with `post`, whose
+/// hash is `hash`, and records the outcome in our DB. The row is removed if
+/// the message is gone and could not be reposted.
+pub async fn publish<T: Tracked>(
+    http: &Http,
+    db: &DbConn,
+    row: T,
+    post: Post,
+    hash: i64,
+) -> eyre::Result<()>
+where
+    T::Entity: EntityTrait<Model = T>,
+{
+    match edit(http, row.channel_id(), row.message_id(), row.sticky(), post).await? {
+        Edit::Edited => {
+            let mut active = row.into_active_model();
+            T::set_content_hash(&mut active, Some(hash));
+            active.update(db).await?;
+        }
+        Edit::Reposted(message_id) => {
+            let mut active = row.into_active_model();
+            T::set_message_id(&mut active, message_id.0 as i64);
+            T::set_content_hash(&mut active, Some(hash));
+            active.update(db).await?;
+        }
+        Edit::Gone => {
+            // The message must have been deleted or we no longer have permission to find it.
+            // Remove from our DB, logging but not failing on error.
+            if let Err(e) = row.delete(db).await {
+                eprintln!("Warning: Unable to delete stale message from our DB: {}", e);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// The HTTP status code of a failed Discord request, if that is what `e` is.
+pub fn status_code(e: &serenity::Error) -> Option<StatusCode> {
+    match e {
+        serenity::Error::Http(http) => http.status_code(),
+        _ => None,
+    }
+}
+
+/// Whether the message we tried to access has been deleted or we no longer
+/// have permission to see it.
+pub fn is_gone(e: &serenity::Error) -> bool {
+    matches!(
+        status_code(e),
+        Some(StatusCode::FORBIDDEN | StatusCode::NOT_FOUND)
+    )
+}
+
+/// Rate limits and server-side errors are worth retrying; anything else is not.
+fn is_retryable(e: &eyre::Report) -> bool {
+    e.downcast_ref::<serenity::Error>()
+        .and_then(status_code)
+        .is_some_and(|status| status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error())
+}
@@ -0,0 +1,316 @@
+use chrono::{DateTime, Utc};
+use poise::serenity_prelude::{ChannelId, GuildId, Http};
+use sea_orm::{ActiveModelTrait, ColumnTrait, DbConn, EntityTrait, QueryFilter, Set};
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::refresh::Queue;
+use crate::srank;
+use crate::train::{end_maintenance, event, plan, refresh_trains, start_maintenance};
+use entity::expiry_rule::Expiry;
+use entity::force_alert::{self, AlertKind};
+use entity::locale::{Locale, Text};
+use entity::template::{TemplateKey, Templates, Vars};
+use entity::theme::Theme;
+use entity::{
+    maintenance_window, notice_channel, recurring_train, s_rank, scheduled_train, train, World,
+};
+
+/// How often the scheduler checks for anything that has become due.
+const INTERVAL: Duration = Duration::from_secs(60);
+
+/// Everything the scheduler needs to act on its own.
+struct Scheduler {
+    http: Arc<Http>,
+    db: DbConn,
+    queue: Queue,
+    guild_id: GuildId,
+    /// When the scheduler last ran; anything timed since then is due.
+    last_tick: DateTime<Utc>,
+}
+
+impl Scheduler {
+    /// Runs anything that is due, printing problems to stderr.
+    async fn tick(&mut self, now: DateTime<Utc>) {
+        if let Err(e) = self.apply_maintenance(now).await {
+            eprintln!("Warning: Unable to apply scheduled maintenance: {}", e);
+        }
+        if let Err(e) = self.plan_recurring(now).await {
+            eprintln!("Warning: Unable to plan recurring trains: {}", e);
+        }
+        if let Err(e) = self.send_reminders(now).await {
+            eprintln!("Warning: Unable to send train reminders: {}", e);
+        }
+        if let Err(e) = self.drop_lapsed(now).await {
+            eprintln!("Warning: Unable to drop lapsed planned trains: {}", e);
+        }
+        if let Err(e) = self.send_force_alerts(self.last_tick, now).await {
+            eprintln!("Warning: Unable to send force alerts: {}", e);
+        }
+        if let Err(e) = self.refresh_expired(self.last_tick, now).await {
+            eprintln!("Warning: Unable to refresh expired trains: {}", e);
+        }
+        if let Err(e) = self.refresh_s_ranks(self.last_tick, now).await {
+            eprintln!("Warning: Unable to refresh S-rank dashboards: {}", e);
+        }
+        self.last_tick = now;
+    }
+
+    /// Starts and ends maintenance windows whose time has come. A window that
+    /// was missed entirely, e.g. while the bot was down, is started and ended
+    /// in the same tick.
+    async fn apply_maintenance(&self, now: DateTime<Utc>) -> eyre::Result<()> {
+        let windows = maintenance_window::Entity::find()
+            .filter(maintenance_window::Column::Ended.eq(false))
+            .filter(maintenance_window::Column::StartTime.lte(now))
+            .all(&self.db)
+            .await?;
+        for window in windows {
+            let mut active = maintenance_window::ActiveModel::from(window.clone());
+            if !window.started {
+                start_maintenance(&self.db, &self.queue).await?;
+                active.started = Set(true);
+                active = active.save(&self.db).await?;
+                self.notify(Text::MaintenanceStarted).await;
+            }
+            if window.end_time <= now {
+                end_maintenance(&self.db, &self.queue, window.end_time).await?;
+                active.ended = Set(true);
+                active.save(&self.db).await?;
+                self.notify(Text::MaintenanceEnded).await;
+            }
+        }
+        Ok(())
+    }
+
+    /// Posts the force alerts that fell due between `since` and `now`, and
+    /// refreshes the posts of trains that have been forced so that they show
+    /// as ready.
+    async fn send_force_alerts(
+        &self,
+        since: DateTime<Utc>,
+        now: DateTime<Utc>,
+    ) -> eyre::Result<()> {
+        let due = |time: DateTime<Utc>| since < time && time <= now;
+        let trains: Vec<_> = train::Entity::find()
+            .filter(train::Column::World.ne(World::Testing))
+            .all(&self.db)
+            .await?
+            .into_iter()
+            .filter(|t| t.force_time().is_some())
+            .collect();
+        let forced: Vec<_> = trains
+            .iter()
+            .filter(|t| t.force_time().is_some_and(due))
+            .cloned()
+            .collect();
+        if !forced.is_empty() {
+            refresh_trains(&self.db, &self.queue, &forced).await;
+        }
+
+        let alerts = force_alert::Entity::find().all(&self.db).await?;
+        if alerts.is_empty() {
+            return Ok(());
+        }
+        let guild_id = self.guild_id.0 as i64;
+        let theme = Theme::load(&self.db, guild_id).await?;
+        let locale = Locale::for_guild(&self.db, guild_id).await?;
+        let templates = Templates::load(&self.db, guild_id, locale).await?;
+        for alert in alerts {
+            let (key, lead) = match alert.kind {
+                AlertKind::Soon => (
+                    TemplateKey::ForceSoon,
+                    chrono::Duration::minutes(alert.minutes),
+                ),
+                AlertKind::Forced => (TemplateKey::Forced, chrono::Duration::zero()),
+            };
+            let lines: Vec<_> = trains
+                .iter()
+                .filter(|t| {
+                    t.force_time()
+                        .and_then(|force| force.checked_sub_signed(lead))
+                        .is_some_and(due)
+                })
+                .map(|t| templates.render(key, &Vars::train(t, &theme, locale)))
+                .collect();
+            if lines.is_empty() {
+                continue;
+            }
+            let mut text = match alert.role_id {
+                Some(role_id) => format!("<@&{}>", role_id),
+                None => String::new(),
+            };
+            for line in lines {
+                // Messages are limited to 2000 characters.
+                if text.chars().count() + line.chars().count() + 1 > 2000 {
+                    break;
+                }
+                if !text.is_empty() {
+                    text.push('\n');
+                }
+                text.push_str(&line);
+            }
+            let channel = ChannelId(alert.channel_id as u64);
+            if let Err(e) = channel.say(&self.http, text).await {
+                eprintln!("Warning: Unable to post force alert to {}: {}", channel, e);
+            }
+        }
+        Ok(())
+    }
+
+    /// Refreshes the posts of trains whose status expired between `since` and
+    /// `now`, so that they show as stale.
+    async fn refresh_expired(&self, since: DateTime<Utc>, now: DateTime<Utc>) -> eyre::Result<()> {
+        let expiry = Expiry::load(&self.db, self.guild_id.0 as i64).await?;
+        let expired: Vec<_> = train::Entity::find()
+            .filter(train::Column::World.ne(World::Testing))
+            .all(&self.db)
+            .await?
+            .into_iter()
+            .filter(|t| {
+                expiry
+                    .expires_at(t)
+                    .is_some_and(|time| since < time && time <= now)
+            })
+            .collect();
+        if !expired.is_empty() {
+            refresh_trains(&self.db, &self.queue, &expired).await;
+        }
+        Ok(())
+    }
+
+    /// Refreshes the S-rank dashboards once a window has opened or closed
+    /// between `since` and `now`.
+    async fn refresh_s_ranks(&self, since: DateTime<Utc>, now: DateTime<Utc>) -> eyre::Result<()> {
+        let s_ranks = s_rank::Entity::find().all(&self.db).await?;
+        let changed = s_ranks.iter().any(|s| {
+            s.window_changes()
+                .into_iter()
+                .any(|time| since < time && time <= now)
+        });
+        if changed {
+            srank::refresh_dashboards(&self.db, &self.queue, None).await;
+        }
+        Ok(())
+    }
+
+    /// Plans the upcoming occurrences of recurring trains.
+    async fn plan_recurring(&self, now: DateTime<Utc>) -> eyre::Result<()> {
+        let rules = recurring_train::Entity::find()
+            .filter(recurring_train::Column::Paused.eq(false))
+            .all(&self.db)
+            .await?;
+        let mut trains = Vec::new();
+        for rule in rules {
+            let train_id = rule.train_id;
+            let planned = plan::generate(&self.http, &self.db, self.guild_id, rule, now).await?;
+            if !planned.is_empty() {
+                trains.extend(train::Entity::find_by_id(train_id).one(&self.db).await?);
+            }
+        }
+        if !trains.is_empty() {
+            refresh_trains(&self.db, &self.queue, &trains).await;
+        }
+        Ok(())
+    }
+
+    /// Reminds conductors of their planned trains, in the channel each train
+    /// was planned in.
+    async fn send_reminders(&self, now: DateTime<Utc>) -> eyre::Result<()> {
+        let due: Vec<_> = scheduled_train::upcoming(now)
+            .filter(scheduled_train::Column::Reminded.eq(false))
+            .find_also_related(train::Entity)
+            .all(&self.db)
+            .await?
+            .into_iter()
+            .filter(|(scheduled, _)| scheduled.remind_time() <= now)
+            .collect();
+        if due.is_empty() {
+            return Ok(());
+        }
+        let guild_id = self.guild_id.0 as i64;
+        let theme = Theme::load(&self.db, guild_id).await?;
+        let locale = Locale::for_guild(&self.db, guild_id).await?;
+        let templates = Templates::load(&self.db, guild_id, locale).await?;
+        for (scheduled, train) in due {
+            if let Some(train) = train {
+                let vars = Vars::schedule(&train, &scheduled, &theme, locale);
+                let text = templates.render(TemplateKey::Reminder, &vars);
+                let channel = ChannelId(scheduled.channel_id as u64);
+                if let Err(e) = channel.say(&self.http, text.trim_end()).await {
+                    eprintln!("Warning: Unable to post reminder to {}: {}", channel, e);
+                }
+            }
+            // Not retried on failure, so that a missing channel does not keep
+            // the reminder coming back.
+            let mut scheduled = scheduled_train::ActiveModel::from(scheduled);
+            scheduled.reminded = Set(true);
+            scheduled.update(&self.db).await?;
+        }
+        Ok(())
+    }
+
+    /// Deletes planned trains that were never started, and takes them off
+    /// their posts.
+    async fn drop_lapsed(&self, now: DateTime<Utc>) -> eyre::Result<()> {
+        let lapsed: Vec<_> = scheduled_train::Entity::find()
+            .filter(scheduled_train::Column::StartedAt.is_null())
+            .filter(scheduled_train::Column::StartTime.lte(now - scheduled_train::grace()))
+            .find_also_related(train::Entity)
+            .all(&self.db)
+            .await?;
+        if lapsed.is_empty() {
+            return Ok(());
+        }
+        let mut trains = Vec::new();
+        for (scheduled, train) in lapsed {
+            event::cancel(&self.http, self.guild_id, &scheduled).await;
+            scheduled_train::Entity::delete_by_id(scheduled.id)
+                .exec(&self.db)
+                .await?;
+            trains.extend(train);
+        }
+        refresh_trains(&self.db, &self.queue, &trains).await;
+        Ok(())
+    }
+
+    /// Posts `text` to every notice channel, in the guild's language.
+    async fn notify(&self, text: Text) {
+        let result = futures::try_join!(
+            notice_channel::Entity::find().all(&self.db),
+            Locale::for_guild(&self.db, self.guild_id.0 as i64),
+        );
+        let (channels, locale) = match result {
+            Ok(rows) => rows,
+            Err(e) => {
+                eprintln!("Warning: Unable to retrieve notice channels from DB: {}", e);
+                return;
+            }
+        };
+        for channel in channels {
+            let id = ChannelId(channel.channel_id as u64);
+            if let Err(e) = id.say(&self.http, locale.text(text)).await {
+                eprintln!("Warning: Unable to post notice to {}: {}", id, e);
+            }
+        }
+    }
+}
+
+/// Checks for due scheduled work immediately and then periodically in the
+/// background.
+pub fn spawn(http: Arc<Http>, db: DbConn, queue: Queue, guild_id: GuildId) {
+    let mut scheduler = Scheduler {
+        http,
+        db,
+        queue,
+        guild_id,
+        last_tick: Utc::now(),
+    };
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(INTERVAL);
+        loop {
+            interval.tick().await;
+            scheduler.tick(Utc::now()).await;
+        }
+    });
+}
@@ -0,0 +1,116 @@
+use chrono::{DateTime, Utc};
+use poise::serenity_prelude::{CreateComponents, GuildId, Http};
+use sea_orm::{DbConn, EntityTrait, Iterable};
+use std::collections::HashMap;
+
+use crate::grid::Grid;
+use crate::refresh::{self, Post, Target};
+use entity::locale::{Locale, Text};
+use entity::mark::Window;
+use entity::s_rank::{self, ZONES};
+use entity::{s_rank_dashboard, World};
+
+pub mod command;
+
+/// Text for the S-rank of one zone on one world in a dashboard grid.
+fn dashboard_cell(s_rank: Option<&s_rank::Model>, now: DateTime<Utc>) -> String {
+    match s_rank.map_or(Window::Unknown, |s| s.window(now)) {
+        Window::Unknown => "❓".to_owned(),
+        Window::Closed { opens } => format!("🕑 <t:{}:R>", opens.timestamp()),
+        Window::Open { forced } => format!("🟢 <t:{}:R>", forced.timestamp()),
+        Window::Forced { .. } => "🔥".to_owned(),
+    }
+}
+
+fn render_dashboard(
+    dashboard: &s_rank_dashboard::Model,
+    s_ranks: &[s_rank::Model],
+    locale: Locale,
+) -> Post {
+    let now = Utc::now();
+    let s_rank_map: HashMap<_, _> = s_ranks
+        .iter()
+        .map(|s| ((s.world, s.zone.as_str()), s))
+        .collect();
+    let zones: Vec<_> = ZONES
+        .iter()
+        .copied()
+        .filter(|&zone| dashboard.shows_zone(zone))
+        .collect();
+    let worlds: Vec<_> = World::iter()
+        .filter(|&world| dashboard.shows_world(world))
+        .collect();
+
+    let grid = Grid {
+        title: locale.text(Text::SRankDashboardTitle).to_owned(),
+        corner: locale.text(Text::Zone).to_owned(),
+        colour: None,
+        sections: Vec::new(),
+        locale,
+        row_labels: zones
+            .iter()
+            .map(|zone| format!("__{}__", zone.name))
+            .collect(),
+        columns: worlds
+            .iter()
+            .map(|&world| {
+                let cells = zones
+                    .iter()
+                    .map(|zone| dashboard_cell(s_rank_map.get(&(world, zone.name)).copied(), now))
+                    .collect();
+                (world.to_string(), cells)
+            })
+            .collect(),
+    };
+    Post {
+        content: locale.text(Text::SRankNote).to_owned(),
+        embeds: grid.into_embeds(),
+        components: CreateComponents::default(),
+    }
+}
+
+pub(crate) async fn refresh_dashboard(
+    http: &Http,
+    db: &DbConn,
+    guild_id: GuildId,
+    id: i64,
+) -> eyre::Result<()> {
+    let Some(dashboard) = s_rank_dashboard::Entity::find_by_id(id).one(db).await? else {
+        // Deleted since the refresh was queued.
+        return Ok(());
+    };
+    let s_ranks = s_rank::Entity::find().all(db).await?;
+    let locale = Locale::for_guild(db, guild_id.0 as i64).await?;
+    let mut post = render_dashboard(&dashboard, &s_ranks, locale);
+    let hash = post.hash();
+    if dashboard.content_hash == Some(hash) {
+        return Ok(());
+    }
+    // Added after hashing so that it shows when the content last changed.
+    if let Some(embed) = post.embeds.last_mut() {
+        embed.timestamp(Utc::now());
+    }
+
+    refresh::publish(http, db, dashboard, post, hash).await
+}
+
+/// Refreshes every S-rank dashboard that shows `world` through the refresh
+/// queue, or every one of them if `world` is None.
+// Prints errors to stderr and reports only success/failure.
+pub async fn refresh_dashboards(db: &DbConn, queue: &refresh::Queue, world: Option<World>) -> bool {
+    let dashboards = match s_rank_dashboard::Entity::find().all(db).await {
+        Ok(dashboards) => dashboards,
+        Err(e) => {
+            eprintln!(
+                "Warning: Unable to retrieve S-rank dashboards from DB: {}",
+                e
+            );
+            return false;
+        }
+    };
+    let targets = dashboards
+        .iter()
+        .filter(|d| world.is_none_or(|world| d.shows_world(world)))
+        .map(|d| Target::SRankDashboard(d.id));
+    queue.refresh(targets).await
+}
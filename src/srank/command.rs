@@ -0,0 +1,255 @@
+use chrono::Utc;
+use eyre::{bail, eyre};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, EntityTrait, Iterable, NotSet, QueryFilter, Set,
+    TransactionTrait,
+};
+
+use super::refresh_dashboards;
+use crate::command::{argument, autocomplete, reply_locale, Context};
+use crate::refresh::Target;
+use crate::train::command::{list_description, parse_filter};
+use entity::locale::Text;
+use entity::mark::Window;
+use entity::s_rank::{self, Zone, ZONES};
+use entity::template::{render, Vars};
+use entity::{s_rank_dashboard, Expac, World};
+
+/// Longest spawn-condition notes accepted for an S-rank.
+const MAX_NOTES_LEN: usize = 300;
+
+/// Track S-rank spawn windows.
+#[poise::command(
+    slash_command,
+    subcommands("killed", "status", "set", "create_dashboard")
+)]
+pub async fn srank(_ctx: Context<'_>) -> eyre::Result<()> {
+    Err(eyre!("unsupported"))
+}
+
+fn reply_msg(base: String, success: bool) -> String {
+    if success {
+        format!("{}.", base)
+    } else {
+        format!(
+            "Error: {}, but not all S-rank dashboards could be updated.",
+            base
+        )
+    }
+}
+
+/// Report an S-rank as killed
+#[poise::command(slash_command)]
+pub async fn killed(
+    ctx: Context<'_>,
+    #[autocomplete = "autocomplete::zone"]
+    #[description = "Zone of the S-rank"]
+    zone: Zone,
+    #[autocomplete = "autocomplete::world"]
+    #[description = "World server"]
+    world: World,
+    #[description = "When it was killed, e.g. \"10m ago\" or \"21:30\" (default: now)"]
+    kill_time: Option<argument::Timestamp>,
+) -> eyre::Result<()> {
+    let kill_time = match kill_time {
+        Some(kill_time) => kill_time.resolve(ctx).await?,
+        None => Utc::now(),
+    };
+    let db = &ctx.data().db;
+    let s_rank = s_rank::find_or_create(db, world, zone).await?;
+    let mut s_rank = s_rank::ActiveModel::from(s_rank);
+    s_rank.last_kill = Set(Some(kill_time));
+    let s_rank = s_rank.update(db).await?;
+
+    ctx.defer().await?;
+    let success = refresh_dashboards(db, &ctx.data().refresh, Some(world)).await;
+    let opens = kill_time + chrono::Duration::hours(s_rank.min_hours);
+    let locale = reply_locale(ctx).await?;
+    let vars = Vars::default()
+        .with("zone", zone.to_string())
+        .with("world", world.to_string())
+        .with("kill_time", format!("<t:{}:f>", kill_time.timestamp()))
+        .with("opens", format!("<t:{}:R>", opens.timestamp()));
+    ctx.say(reply_msg(
+        render(locale.text(Text::SRankKilled), &vars),
+        success,
+    ))
+    .await?;
+    Ok(())
+}
+
+/// One S-rank's window, as listed by `/srank status`.
+fn status_line(zone: Zone, s_rank: Option<&s_rank::Model>) -> String {
+    let window = match s_rank.map_or(Window::Unknown, |s| s.window(Utc::now())) {
+        Window::Unknown => "❓ no kill reported".to_owned(),
+        Window::Closed { opens } => format!("🕑 window opens <t:{}:R>", opens.timestamp()),
+        Window::Open { forced } => {
+            format!("🟢 in window, latest spawn <t:{}:R>", forced.timestamp())
+        }
+        Window::Forced { since } => format!("🔥 past the latest spawn <t:{}:R>", since.timestamp()),
+    };
+    let mut line = format!("**{}**: {}", zone, window);
+    if let Some(notes) = s_rank.and_then(|s| s.notes.as_ref()) {
+        line.push_str(&format!("\n> {}", notes));
+    }
+    line
+}
+
+/// Show the S-rank windows of a world
+#[poise::command(slash_command, ephemeral)]
+pub async fn status(
+    ctx: Context<'_>,
+    #[autocomplete = "autocomplete::world"]
+    #[description = "World server"]
+    world: World,
+    #[autocomplete = "autocomplete::expac"]
+    #[description = "Only show this expansion (default: all)"]
+    expac: Option<Expac>,
+) -> eyre::Result<()> {
+    let s_ranks = s_rank::Entity::find()
+        .filter(s_rank::Column::World.eq(world))
+        .all(&ctx.data().db)
+        .await?;
+    let lines = ZONES
+        .iter()
+        .copied()
+        .filter(|zone| expac.is_none_or(|expac| zone.expac == expac))
+        .map(|zone| status_line(zone, s_ranks.iter().find(|s| s.zone == zone.name)))
+        .collect();
+    ctx.send(|m| {
+        m.embed(|e| {
+            e.title(format!("{} S-Ranks", world))
+                .description(list_description(lines, "No zones to show."))
+        })
+    })
+    .await?;
+    Ok(())
+}
+
+/// Set the respawn window and spawn conditions of an S-rank
+#[poise::command(slash_command, required_permissions = "MANAGE_GUILD")]
+pub async fn set(
+    ctx: Context<'_>,
+    #[autocomplete = "autocomplete::zone"]
+    #[description = "Zone of the S-rank"]
+    zone: Zone,
+    #[autocomplete = "autocomplete::world"]
+    #[description = "Only change this world (default: all)"]
+    world: Option<World>,
+    #[description = "Hours after a kill before it can spawn again"]
+    #[min = 1]
+    #[max = 336]
+    min_hours: Option<i64>,
+    #[description = "Hours after a kill by which it can certainly spawn again"]
+    #[min = 1]
+    #[max = 336]
+    max_hours: Option<i64>,
+    #[description = "What has to be done for it to spawn (\"-\" to clear)"] notes: Option<String>,
+) -> eyre::Result<()> {
+    if ctx.guild_id() != Some(ctx.data().train_guild_id) {
+        bail!("Not allowed in this guild/in DM");
+    }
+    if min_hours.is_none() && max_hours.is_none() && notes.is_none() {
+        bail!("Provide a window, notes or both");
+    }
+    let notes = notes.map(|n| n.trim().to_owned());
+    if notes
+        .as_ref()
+        .is_some_and(|n| n.chars().count() > MAX_NOTES_LEN)
+    {
+        bail!("Notes are too long (at most {} characters)", MAX_NOTES_LEN);
+    }
+
+    if let (Some(min), Some(max)) = (min_hours, max_hours) {
+        if max < min {
+            bail!("The window has to end after it opens");
+        }
+    }
+
+    let db = &ctx.data().db;
+    let worlds: Vec<_> = match world {
+        Some(world) => vec![world],
+        None => World::iter()
+            .filter(|w| w.data_center().is_some())
+            .collect(),
+    };
+    // Either every world is changed or, if one of them would end up with an
+    // invalid window, none are.
+    let tx = db.begin().await?;
+    for &world in &worlds {
+        let s_rank = s_rank::find_or_create(&tx, world, zone).await?;
+        let min = min_hours.unwrap_or(s_rank.min_hours);
+        let max = max_hours.unwrap_or(s_rank.max_hours);
+        if max < min {
+            bail!(
+                "The window on {} would end before it opens ({}h to {}h)",
+                world,
+                min,
+                max
+            );
+        }
+        let mut s_rank = s_rank::ActiveModel::from(s_rank);
+        s_rank.min_hours = Set(min);
+        s_rank.max_hours = Set(max);
+        if let Some(notes) = &notes {
+            s_rank.notes = Set((notes != "-" && !notes.is_empty()).then(|| notes.clone()));
+        }
+        s_rank.update(&tx).await?;
+    }
+    tx.commit().await?;
+
+    ctx.defer().await?;
+    let success = refresh_dashboards(db, &ctx.data().refresh, world).await;
+    let worlds = match world {
+        Some(world) => world.to_string(),
+        None => "every world".to_owned(),
+    };
+    ctx.say(reply_msg(
+        format!("{} S-rank updated on {}", zone, worlds),
+        success,
+    ))
+    .await?;
+    Ok(())
+}
+
+/// Add a new S-rank dashboard
+#[poise::command(slash_command, required_permissions = "MANAGE_MESSAGES")]
+pub async fn create_dashboard(
+    ctx: Context<'_>,
+    #[description = "Comma-separated worlds to show (default: all)"] worlds: Option<String>,
+    #[description = "Comma-separated expansions to show (default: all)"] expacs: Option<String>,
+    #[description = "Repost the dashboard if it is deleted (default: no)"] sticky: Option<bool>,
+) -> eyre::Result<()> {
+    if ctx.guild_id() != Some(ctx.data().train_guild_id) {
+        bail!("Not allowed in this guild/in DM");
+    }
+    let worlds = parse_filter(worlds.as_deref().unwrap_or("all"), World::lookup)?;
+    let expacs = parse_filter(expacs.as_deref().unwrap_or("all"), Expac::lookup)?;
+
+    // Send an "initializing" message first so that we can get its ID.
+    let msg = ctx
+        .say("Initializing S-rank dashboard...")
+        .await?
+        .into_message()
+        .await?;
+    let dashboard = s_rank_dashboard::ActiveModel {
+        id: NotSet,
+        channel_id: Set(ctx.channel_id().0 as i64),
+        message_id: Set(msg.id.0 as i64),
+        sticky: Set(sticky.unwrap_or(false)),
+        worlds: Set(worlds),
+        expacs: Set(expacs),
+        ..Default::default()
+    };
+    let dashboard = dashboard.insert(&ctx.data().db).await?;
+
+    let refreshed = ctx
+        .data()
+        .refresh
+        .refresh([Target::SRankDashboard(dashboard.id)])
+        .await;
+    if !refreshed {
+        bail!("Unable to initialize S-rank dashboard");
+    }
+    Ok(())
+}
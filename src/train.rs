@@ -1,213 +1,569 @@
-use chrono::{Duration, Utc};
-use futures::{stream::FuturesUnordered, StreamExt};
-use poise::serenity_prelude::{ChannelId, MessageId};
-use sea_orm::{ColumnTrait, EntityTrait, ModelTrait, QueryFilter};
-use std::collections::{BTreeSet, HashMap};
-use std::convert::AsRef;
-use std::fmt::Write;
+use chrono::{DateTime, Utc};
+use poise::serenity_prelude::{Colour, CreateComponents, CreateEmbed, GuildId, Http};
+use sea_orm::{ActiveModelTrait, ColumnTrait, DbConn, EntityTrait, QueryFilter, TransactionTrait};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 
 use crate::command::Context;
+use crate::grid::Grid;
+use crate::refresh::{self, Post, Target};
 use entity::{
-    dashboard, monitor,
+    dashboard,
+    expiry_rule::{stale_label, Expiry, STALE_EMOJI},
+    locale::{Locale, Text},
+    monitor, s_rank_dashboard, scheduled_train,
+    template::{TemplateKey, Templates},
+    theme::Theme,
     train::{self, Status},
-    Expac, World,
+    DataCenter, Expac, World,
 };
 
 pub mod command;
+pub mod event;
+pub mod plan;
 
-async fn refresh_dashboard(
-    ctx: Context<'_>,
-    dashboard: dashboard::Model,
-    trains: impl AsRef<[train::Model]>,
-) -> eyre::Result<()> {
-    let db = &ctx.data().db;
-    let channel_id = ChannelId(dashboard.channel_id as u64);
-    let message_id = MessageId(dashboard.message_id as u64);
-    let msg = channel_id.message(ctx.discord(), message_id).await;
-    if let Err(serenity::Error::Http(ref http)) = msg {
-        if let serenity::http::error::Error::UnsuccessfulRequest(
-            serenity::http::error::ErrorResponse {
-                status_code:
-                    poise::serenity_prelude::StatusCode::FORBIDDEN
-                    | poise::serenity_prelude::StatusCode::NOT_FOUND,
-                ..
-            },
-        ) = **http
-        {
-            // The message must have been deleted or we no longer have permission to find it.
-            // Remove from our DB, logging but not failing on error.
-            if let Err(e) = dashboard.delete(db).await {
-                eprintln!("Warning: Unable to delete stale message from our DB: {}", e);
+/// Embed fields are limited to 1024 characters.
+const MAX_FIELD_VALUE: usize = 1024;
+/// Room for the schedule below a dashboard, out of the 6000 characters the
+/// embeds of a post may have in total.
+const MAX_SCHEDULE_CHARS: usize = 1500;
+
+/// The status a train is counted as: unknown once its status has expired.
+fn shown_status(train: &train::Model, expiry: &Expiry, now: DateTime<Utc>) -> Status {
+    if expiry.is_stale(train, now) {
+        Status::Unknown
+    } else {
+        train.status
+    }
+}
+
+/// Text for a single train in a dashboard grid.
+fn dashboard_cell(
+    train: Option<&train::Model>,
+    stale: bool,
+    compact: bool,
+    theme: &Theme,
+    locale: Locale,
+) -> String {
+    let status = train.map_or(Status::Unknown, |t| t.status);
+    if stale {
+        // Italics set expired statuses apart from current ones.
+        return match compact {
+            true => STALE_EMOJI.to_owned(),
+            false => format!("{} *{}*", STALE_EMOJI, stale_label(status, locale)),
+        };
+    }
+    let mapped = || match train.and_then(|t| t.scout_map.as_ref()) {
+        Some(url) => format!("[{}]({})", locale.status(status), url),
+        None => locale.status(status).to_owned(),
+    };
+    let text = match status {
+        Status::Scouted | Status::Abandoned => mapped(),
+        Status::Partial => match train.and_then(|t| t.marks_left) {
+            Some(marks_left) => format!("{} ({})", mapped(), marks_left),
+            None => mapped(),
+        },
+        Status::Running => format!("**{}**", locale.status(status)),
+        Status::Maintenance => match train.and_then(train::Model::force_time) {
+            Some(force_time) if compact => format!("<t:{}:R>", force_time.timestamp()),
+            Some(force_time) => {
+                format!("{} <t:{}:R>", locale.status(status), force_time.timestamp())
             }
-            return Ok(());
+            None => locale.status(status).to_owned(),
+        },
+        _ => match train.and_then(train::Model::force_time) {
+            Some(force_time) => format!("<t:{}:R>", force_time.timestamp()),
+            None if compact => return theme.emoji(status).to_owned(),
+            None => locale.status(Status::Unknown).to_owned(),
+        },
+    };
+    format!("{} {}", theme.emoji(status), text)
+}
+
+/// Colour for a post showing `statuses`, picked from the most active one.
+fn summary_colour(theme: &Theme, statuses: impl IntoIterator<Item = Status>) -> Option<Colour> {
+    let statuses: Vec<_> = statuses.into_iter().collect();
+    [
+        Status::Running,
+        Status::Scouted,
+        Status::Partial,
+        Status::Abandoned,
+        Status::Maintenance,
+        Status::Waiting,
+        Status::Sniped,
+        Status::Unknown,
+    ]
+    .into_iter()
+    .find(|status| statuses.contains(status))
+    .and_then(|status| theme.colour(status))
+}
+
+/// One planned train, as listed on monitors and dashboards. `train` names the
+/// train, where the post shows more than one.
+fn schedule_line(scheduled: &scheduled_train::Model, train: Option<String>) -> String {
+    let mut line = format!("<t:{0}:f> (<t:{0}:R>) ", scheduled.start_time.timestamp());
+    if let Some(train) = train {
+        line.push_str(&format!("**{}** ", train));
+    }
+    line.push_str(&format!("<@{}>", scheduled.conductor));
+    if let Some(notes) = &scheduled.notes {
+        line.push_str(" · ");
+        line.push_str(notes);
+    }
+    line
+}
+
+/// Joins `lines`, leaving out whatever does not fit in `limit` characters.
+fn schedule_text(lines: impl IntoIterator<Item = String>, limit: usize) -> String {
+    let mut text = String::new();
+    for line in lines {
+        if text.chars().count() + line.chars().count() + 1 > limit {
+            break;
+        }
+        if !text.is_empty() {
+            text.push('\n');
         }
+        text.push_str(&line);
     }
+    text
+}
 
+fn render_dashboard(
+    dashboard: &dashboard::Model,
+    trains: &[train::Model],
+    schedule: &[(scheduled_train::Model, train::Model)],
+    theme: &Theme,
+    expiry: &Expiry,
+    templates: &Templates,
+) -> Post {
+    let now = Utc::now();
     let mut expacs = BTreeSet::new();
     let mut worlds = BTreeSet::new();
     let mut train_map = HashMap::<(Expac, World), &train::Model>::new();
-    for t in trains.as_ref() {
+    for t in trains {
+        if !dashboard.shows_world(t.world) || !dashboard.shows_expac(t.expac) {
+            continue;
+        }
         train_map.insert((t.expac, t.world), t);
         expacs.insert(t.expac);
         worlds.insert(t.world);
     }
+    let expacs: Vec<_> = expacs.into_iter().rev().collect();
+    let mut worlds: Vec<_> = worlds.into_iter().collect();
+    if dashboard.sort_by_force {
+        // Worlds without any pending force time go last; the sort is stable,
+        // so ties stay in name order.
+        worlds.sort_by_key(|&world| {
+            let soonest = expacs
+                .iter()
+                .filter_map(|&expac| train_map.get(&(expac, world))?.force_time())
+                .min();
+            (soonest.is_none(), soonest)
+        });
+    }
+
+    let locale = templates.locale();
+    let expac_label = |expac: Expac| {
+        if dashboard.compact {
+            expac.short_name().to_owned()
+        } else {
+            locale.expac(expac).to_owned()
+        }
+    };
+    let cell = |world: World, expac: Expac| {
+        let train = train_map.get(&(expac, world)).copied();
+        dashboard_cell(
+            train,
+            train.is_some_and(|t| expiry.is_stale(t, now)),
+            dashboard.compact,
+            theme,
+            locale,
+        )
+    };
+    // Row labels are underlined to set them apart from the cells.
+    let row_label = |label: String| {
+        if dashboard.compact {
+            label
+        } else {
+            format!("__{}__", label)
+        }
+    };
+
+    let schedule = schedule_text(
+        schedule
+            .iter()
+            .filter(|(_, t)| dashboard.shows_world(t.world) && dashboard.shows_expac(t.expac))
+            .map(|(scheduled, t)| {
+                let name = format!("{} {}", t.world, expac_label(t.expac));
+                schedule_line(scheduled, Some(name))
+            }),
+        MAX_SCHEDULE_CHARS,
+    );
+    let sections = if schedule.is_empty() {
+        Vec::new()
+    } else {
+        vec![(locale.text(Text::Schedule).to_owned(), schedule)]
+    };
+
+    let colour = summary_colour(
+        theme,
+        train_map.values().map(|t| shown_status(t, expiry, now)),
+    );
+    let grid = if dashboard.transposed {
+        Grid {
+            title: locale.text(Text::DashboardTitle).to_owned(),
+            corner: locale.text(Text::World).to_owned(),
+            colour,
+            sections,
+            locale,
+            row_labels: worlds.iter().map(|w| row_label(w.to_string())).collect(),
+            columns: expacs
+                .iter()
+                .map(|&expac| {
+                    let cells = worlds.iter().map(|&world| cell(world, expac)).collect();
+                    (expac_label(expac), cells)
+                })
+                .collect(),
+        }
+    } else {
+        Grid {
+            title: locale.text(Text::DashboardTitle).to_owned(),
+            corner: locale.text(Text::Expansion).to_owned(),
+            colour,
+            sections,
+            locale,
+            row_labels: expacs.iter().map(|&e| row_label(expac_label(e))).collect(),
+            columns: worlds
+                .iter()
+                .map(|&world| {
+                    let cells = expacs.iter().map(|&expac| cell(world, expac)).collect();
+                    (world.to_string(), cells)
+                })
+                .collect(),
+        }
+    };
+
+    let content = if dashboard.compact {
+        String::new()
+    } else {
+        templates.get(TemplateKey::DashboardNote).to_owned()
+    };
+    Post {
+        content,
+        embeds: grid.into_embeds(),
+        components: CreateComponents::default(),
+    }
+}
 
-    msg?.edit(ctx.discord(), |m| {
-        m.content("Where a time is present, it indicates force (cap) time.")
-            .embed(|e| {
-                e.title("Train Dashboard")
-                    .timestamp(Utc::now())
-                    .field(
-                        "Expansion",
-                        format!(
-                            "__{}__",
-                            expacs
-                                .iter()
-                                .rev()
-                                .map(Expac::as_ref)
-                                .collect::<Vec<_>>()
-                                .join("__\n__")
-                        ),
-                        true,
-                    )
-                    .fields(worlds.into_iter().map(|world| {
-                        let mut col = String::new();
-                        for &expac in expacs.iter().rev() {
-                            let train = train_map.get(&(expac, world));
-                            use train::Model as Train;
-                            let status = train.map_or(Status::Unknown, |t| t.status);
-
-                            let text = match (status, train) {
-                                (
-                                    Status::Scouted,
-                                    Some(Train {
-                                        scout_map: Some(url),
-                                        ..
-                                    }),
-                                ) => format!("[Scouted]({})", url),
-                                (Status::Scouted, _) => "Scouted".to_owned(),
-                                (
-                                    Status::Waiting,
-                                    Some(Train {
-                                        last_run: Some(last_run),
-                                        ..
-                                    }),
-                                ) => format!(
-                                    "<t:{}:R>",
-                                    (*last_run + Duration::hours(6)).timestamp()
-                                ),
-                                (Status::Running, _) => "**Running**".to_owned(),
-                                _ => "Unknown".to_owned(),
-                            };
-                            writeln!(col, "{} {}", status.emoji(), text,).unwrap();
-                        }
-                        (world, col, true)
-                    }))
+/// Renders counts of trains by status for every data center.
+fn render_overview(
+    dashboard: &dashboard::Model,
+    trains: &[train::Model],
+    theme: &Theme,
+    expiry: &Expiry,
+    templates: &Templates,
+) -> Post {
+    #[derive(Default)]
+    struct Counts {
+        running: usize,
+        scouted: usize,
+        ready: usize,
+        next_force: Option<DateTime<Utc>>,
+    }
+
+    let now = Utc::now();
+    let mut statuses = Vec::new();
+    let mut counts = BTreeMap::<DataCenter, Counts>::new();
+    for t in trains {
+        let Some(dc) = t.world.data_center() else {
+            continue;
+        };
+        if !dashboard.shows_world(t.world) || !dashboard.shows_expac(t.expac) {
+            continue;
+        }
+        let status = shown_status(t, expiry, now);
+        statuses.push(status);
+        let counts = counts.entry(dc).or_default();
+        match (status, t.force_time()) {
+            (Status::Running, _) => counts.running += 1,
+            // Marks left behind by an unfinished train are as good as scouted.
+            (Status::Scouted | Status::Partial | Status::Abandoned, _) => counts.scouted += 1,
+            (_, Some(force_time)) if force_time <= now => counts.ready += 1,
+            (_, Some(force_time)) => {
+                let next = counts.next_force.map_or(force_time, |t| t.min(force_time));
+                counts.next_force = Some(next);
+            }
+            _ => {}
+        }
+    }
+
+    let locale = templates.locale();
+    let colour = summary_colour(theme, statuses);
+    let grid = Grid {
+        title: locale.text(Text::OverviewTitle).to_owned(),
+        corner: locale.text(Text::Status).to_owned(),
+        colour,
+        sections: Vec::new(),
+        locale,
+        row_labels: vec![
+            format!(
+                "{} {}",
+                theme.emoji(Status::Running),
+                locale.status(Status::Running)
+            ),
+            format!(
+                "{} {}",
+                theme.emoji(Status::Scouted),
+                locale.status(Status::Scouted)
+            ),
+            format!(
+                "{} {}",
+                theme.emoji(Status::Waiting),
+                locale.text(Text::Ready)
+            ),
+            locale.text(Text::NextForced).to_owned(),
+        ],
+        columns: counts
+            .into_iter()
+            .map(|(dc, counts)| {
+                let next_force = match counts.next_force {
+                    Some(t) => format!("<t:{}:R>", t.timestamp()),
+                    None => "-".to_owned(),
+                };
+                let cells = vec![
+                    counts.running.to_string(),
+                    counts.scouted.to_string(),
+                    counts.ready.to_string(),
+                    next_force,
+                ];
+                (dc.to_string(), cells)
             })
-    })
-    .await?;
-    Ok(())
+            .collect(),
+    };
+
+    let content = if dashboard.compact {
+        String::new()
+    } else {
+        templates.get(TemplateKey::OverviewNote).to_owned()
+    };
+    Post {
+        content,
+        embeds: grid.into_embeds(),
+        components: CreateComponents::default(),
+    }
 }
 
-async fn refresh_monitor(
-    ctx: Context<'_>,
-    monitor: monitor::Model,
+fn render_monitor(
     train: &train::Model,
+    schedule: &[scheduled_train::Model],
+    theme: &Theme,
+    expiry: &Expiry,
+    templates: &Templates,
+) -> Post {
+    let mut embed = CreateEmbed::default();
+    let stale = expiry.is_stale(train, Utc::now());
+    train.format_embed(&mut embed, theme, templates, stale);
+    let schedule = schedule_text(
+        schedule
+            .iter()
+            .map(|scheduled| schedule_line(scheduled, None)),
+        MAX_FIELD_VALUE,
+    );
+    if !schedule.is_empty() {
+        embed.field(templates.locale().text(Text::Schedule), schedule, false);
+    }
+    let mut components = CreateComponents::default();
+    train.format_components(&mut components, templates.locale());
+    Post {
+        content: String::new(),
+        embeds: vec![embed],
+        components,
+    }
+}
+
+pub(crate) async fn refresh_dashboard(
+    http: &Http,
+    db: &DbConn,
+    guild_id: GuildId,
+    id: i64,
 ) -> eyre::Result<()> {
-    let db = &ctx.data().db;
-    let channel_id = ChannelId(monitor.channel_id as u64);
-    let message_id = MessageId(monitor.message_id as u64);
-    let msg = channel_id.message(ctx.discord(), message_id).await;
-    if let Err(serenity::Error::Http(ref http)) = msg {
-        if let serenity::http::error::Error::UnsuccessfulRequest(
-            serenity::http::error::ErrorResponse {
-                status_code:
-                    poise::serenity_prelude::StatusCode::FORBIDDEN
-                    | poise::serenity_prelude::StatusCode::NOT_FOUND,
-                ..
-            },
-        ) = **http
-        {
-            // The message must have been deleted or we no longer have permission to find it.
-            // Remove from our DB, logging but not failing on error.
-            if let Err(e) = monitor.delete(db).await {
-                eprintln!("Warning: Unable to delete stale message from our DB: {}", e);
-            }
-            return Ok(());
-        }
+    let Some(dashboard) = dashboard::Entity::find_by_id(id).one(db).await? else {
+        // Deleted since the refresh was queued.
+        return Ok(());
+    };
+    let trains = train::Entity::find()
+        .filter(train::Column::World.ne(World::Testing))
+        .all(db)
+        .await?;
+    let schedule: Vec<_> = scheduled_train::upcoming(Utc::now())
+        .find_also_related(train::Entity)
+        .all(db)
+        .await?
+        .into_iter()
+        .filter_map(|(scheduled, train)| Some((scheduled, train?)))
+        .filter(|(_, train)| train.world != World::Testing)
+        .collect();
+    let theme = Theme::load(db, guild_id.0 as i64).await?;
+    let locale = Locale::for_guild(db, guild_id.0 as i64).await?;
+    let templates = Templates::load(db, guild_id.0 as i64, locale).await?;
+    let expiry = Expiry::load(db, guild_id.0 as i64).await?;
+    let mut post = if dashboard.overview {
+        render_overview(&dashboard, &trains, &theme, &expiry, &templates)
+    } else {
+        render_dashboard(&dashboard, &trains, &schedule, &theme, &expiry, &templates)
+    };
+    let hash = post.hash();
+    if dashboard.content_hash == Some(hash) {
+        return Ok(());
+    }
+    // Added after hashing so that it shows when the content last changed.
+    if let Some(embed) = post.embeds.last_mut() {
+        embed.timestamp(Utc::now());
     }
-    msg?.edit(ctx.discord(), |m| {
-        m.content("")
-            .embed(|e| train.format_embed(e))
-            .components(|c| train.format_components(c))
-    })
-    .await?;
-    Ok(())
+
+    refresh::publish(http, db, dashboard, post, hash).await
 }
 
+pub(crate) async fn refresh_monitor(
+    http: &Http,
+    db: &DbConn,
+    guild_id: GuildId,
+    id: i64,
+) -> eyre::Result<()> {
+    let Some((monitor, Some(train))) = monitor::Entity::find_by_id(id)
+        .find_also_related(train::Entity)
+        .one(db)
+        .await?
+    else {
+        // Deleted since the refresh was queued.
+        return Ok(());
+    };
+    let theme = Theme::load(db, guild_id.0 as i64).await?;
+    let locale = Locale::for_guild(db, guild_id.0 as i64).await?;
+    let templates = Templates::load(db, guild_id.0 as i64, locale).await?;
+    let schedule = scheduled_train::upcoming(Utc::now())
+        .filter(scheduled_train::Column::TrainId.eq(train.id))
+        .all(db)
+        .await?;
+    let expiry = Expiry::load(db, guild_id.0 as i64).await?;
+    let post = render_monitor(&train, &schedule, &theme, &expiry, &templates);
+    let hash = post.hash();
+    if monitor.content_hash == Some(hash) {
+        return Ok(());
+    }
+
+    refresh::publish(http, db, monitor, post, hash).await
+}
+
+/// Refreshes every monitor of `train` and every dashboard through the refresh queue.
 // Prints errors to stderr and reports only success/failure.
-pub async fn refresh_dashboards(ctx: Context<'_>) -> bool {
-    let db = &ctx.data().db;
-    let dashboards = match dashboard::Entity::find().all(db).await {
-        Ok(d) => d,
+pub async fn refresh_train(ctx: Context<'_>, train: &train::Model) -> bool {
+    let data = ctx.data();
+    refresh_trains(&data.db, &data.refresh, std::slice::from_ref(train)).await
+}
+
+/// Refreshes every monitor of `trains` and every dashboard that shows any of
+/// them, as a single batch through the refresh queue.
+// Prints errors to stderr and reports only success/failure.
+pub async fn refresh_trains(db: &DbConn, queue: &refresh::Queue, trains: &[train::Model]) -> bool {
+    let train_ids: Vec<_> = trains.iter().map(|t| t.id).collect();
+    let monitors = match monitor::Entity::find()
+        .filter(monitor::Column::TrainId.is_in(train_ids))
+        .all(db)
+        .await
+    {
+        Ok(m) => m,
         Err(e) => {
-            eprintln!("Warning: Unable to retrieve dashboards from DB: {}", e);
+            eprintln!("Warning: Unable to retrieve monitors from DB: {}", e);
             return false;
         }
     };
-    let trains = match train::Entity::find()
-        .filter(train::Column::World.ne(World::Testing))
-        .all(db)
-        .await
-    {
-        Ok(t) => t,
+    let dashboards = match dashboard::Entity::find().all(db).await {
+        Ok(d) => d,
         Err(e) => {
-            eprintln!("Warning: Unable to retrieve trains from DB: {}", e);
+            eprintln!("Warning: Unable to retrieve dashboards from DB: {}", e);
             return false;
         }
     };
 
-    let tasks: FuturesUnordered<_> = dashboards
-        .into_iter()
-        .map(|dashboard| refresh_dashboard(ctx, dashboard, &trains))
-        .collect();
-    tasks
-        .all(|r| async move {
-            match r {
-                Ok(()) => true,
-                Err(e) => {
-                    eprintln!("Warning: Unable to update dashboard: {}", e);
-                    false
-                }
-            }
+    // Only re-render the dashboards that could show these trains.
+    let dashboards = dashboards.iter().filter(|d| {
+        trains.iter().any(|train| {
+            train.world != World::Testing
+                && d.shows_world(train.world)
+                && d.shows_expac(train.expac)
         })
-        .await
+    });
+    let targets = monitors
+        .iter()
+        .map(|m| Target::Monitor(m.id))
+        .chain(dashboards.map(|d| Target::Dashboard(d.id)));
+    queue.refresh(targets).await
 }
 
+/// Refreshes every monitor and dashboard through the refresh queue, e.g.
+/// after a change to how all of them are displayed.
 // Prints errors to stderr and reports only success/failure.
-pub async fn refresh_monitors(ctx: Context<'_>, train: &train::Model) -> bool {
+pub async fn refresh_all(ctx: Context<'_>) -> bool {
     let db = &ctx.data().db;
-    let monitors = match train.find_related(self::monitor::Entity).all(db).await {
-        Ok(m) => m,
+    let (monitors, dashboards, s_rank_dashboards) = match futures::try_join!(
+        monitor::Entity::find().all(db),
+        dashboard::Entity::find().all(db),
+        s_rank_dashboard::Entity::find().all(db)
+    ) {
+        Ok(rows) => rows,
         Err(e) => {
-            eprintln!("Warning: Unable to retrieve monitors from DB: {}", e);
+            eprintln!("Warning: Unable to retrieve posts from DB: {}", e);
             return false;
         }
     };
 
-    let tasks: FuturesUnordered<_> = monitors
-        .into_iter()
-        .map(|monitor| refresh_monitor(ctx, monitor, train))
-        .collect();
-    tasks
-        .all(|r| async move {
-            match r {
-                Ok(()) => true,
-                Err(e) => {
-                    eprintln!("Warning: Unable to update monitor: {}", e);
-                    false
-                }
-            }
-        })
-        .await
+    let targets = monitors
+        .iter()
+        .map(|m| Target::Monitor(m.id))
+        .chain(dashboards.iter().map(|d| Target::Dashboard(d.id)))
+        .chain(
+            s_rank_dashboards
+                .iter()
+                .map(|d| Target::SRankDashboard(d.id)),
+        );
+    ctx.data().refresh.refresh(targets).await
+}
+
+/// Puts every train into maintenance in a single transaction, then refreshes
+/// their posts. Returns the updated trains and whether the refresh succeeded.
+pub async fn start_maintenance(
+    db: &DbConn,
+    queue: &refresh::Queue,
+) -> eyre::Result<(Vec<train::Model>, bool)> {
+    let tx = db.begin().await?;
+    let mut trains = Vec::new();
+    for mut train in train::Entity::find().all(&tx).await? {
+        train.start_maintenance();
+        trains.push(train::ActiveModel::from(train).update(&tx).await?);
+    }
+    tx.commit().await?;
+    let success = refresh_trains(db, queue, &trains).await;
+    Ok((trains, success))
+}
+
+/// Sets the post-maintenance spawn of every train still in maintenance, like
+/// `start_maintenance`.
+pub async fn end_maintenance(
+    db: &DbConn,
+    queue: &refresh::Queue,
+    end_time: DateTime<Utc>,
+) -> eyre::Result<(Vec<train::Model>, bool)> {
+    let tx = db.begin().await?;
+    let mut trains = Vec::new();
+    for mut train in train::Entity::find()
+        .filter(train::Column::Status.eq(Status::Maintenance))
+        .all(&tx)
+        .await?
+    {
+        train.end_maintenance(end_time);
+        trains.push(train::ActiveModel::from(train).update(&tx).await?);
+    }
+    tx.commit().await?;
+    let success = refresh_trains(db, queue, &trains).await;
+    Ok((trains, success))
 }
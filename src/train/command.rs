@@ -1,20 +1,67 @@
-use chrono::{Duration, Utc};
+use chrono::{DateTime, Duration, Utc};
 use eyre::{bail, eyre};
-use sea_orm::{
-    ActiveModelTrait, ColumnTrait, EntityTrait, NotSet, QueryFilter, Set, TransactionTrait,
-};
+use poise::serenity_prelude::{ChannelId, GuildId, MessageId};
+use sea_orm::{ActiveModelTrait, ConnectionTrait, NotSet, Set, TransactionTrait};
 
-use super::{refresh_dashboard, refresh_dashboards, refresh_monitor, refresh_monitors};
-use crate::command::{argument, Context};
+use super::{event, refresh_train};
+use crate::command::argument::{self, TrainRef};
+use crate::command::{autocomplete, reply_locale, Context};
+use crate::refresh::Target;
+use entity::locale::{Locale, Text};
+use entity::template::{render, TemplateKey, Templates, Vars};
+use entity::theme::Theme;
+use entity::train::Status;
 use entity::{
-    dashboard, monitor, train,
-    Expac, World,
+    dashboard, mark_kill, monitor, scheduled_train, train, DataCenter, Expac, LookupError, World,
 };
 
+mod alerts;
+mod batch;
+mod dashboards;
+mod expiry;
+mod language;
+mod maintenance;
+mod monitors;
+mod schedule;
+mod template;
+mod theme;
+mod timezone;
+
+use alerts::alerts;
+use batch::batch;
+use dashboards::dashboards;
+use expiry::expiry;
+use language::language;
+use maintenance::maintenance;
+use monitors::monitors;
+use schedule::schedule;
+use template::template;
+use theme::theme;
+use timezone::timezone;
+
 /// Hunt train commands.
 #[poise::command(
     slash_command,
-    subcommands("scout", "start", "done", "create_monitor", "create_dashboard")
+    subcommands(
+        "scout",
+        "start",
+        "done",
+        "sniped",
+        "abandon",
+        "batch",
+        "maintenance",
+        "schedule",
+        "alerts",
+        "create_monitor",
+        "create_dashboard",
+        "monitors",
+        "dashboards",
+        "theme",
+        "expiry",
+        "template",
+        "language",
+        "timezone"
+    )
 )]
 pub async fn train(_ctx: Context<'_>) -> eyre::Result<()> {
     Err(eyre!("unsupported"))
@@ -22,10 +69,25 @@ pub async fn train(_ctx: Context<'_>) -> eyre::Result<()> {
 
 /// Add a new hunt train monitor dashboard
 #[poise::command(slash_command)]
-pub async fn create_dashboard(ctx: Context<'_>) -> eyre::Result<()> {
+#[allow(clippy::too_many_arguments)]
+pub async fn create_dashboard(
+    ctx: Context<'_>,
+    #[description = "Only show this data center (default: all)"] data_center: Option<DataCenter>,
+    #[description = "Show counts per data center instead of each train (default: no)"]
+    overview: Option<bool>,
+    #[description = "Repost the dashboard if it is deleted (default: no)"] sticky: Option<bool>,
+    #[description = "Comma-separated worlds to show (default: all)"] worlds: Option<String>,
+    #[description = "Comma-separated expansions to show (default: all)"] expacs: Option<String>,
+    #[description = "Show worlds as rows (default: no)"] transposed: Option<bool>,
+    #[description = "Order worlds by soonest force time (default: no)"] sort_by_force: Option<bool>,
+    #[description = "Leave out explanatory text and use short labels (default: no)"]
+    compact: Option<bool>,
+) -> eyre::Result<()> {
     if ctx.guild_id() != Some(ctx.data().train_guild_id) {
         bail!("Not allowed in this guild/in DM");
     }
+    let worlds = parse_filter(worlds.as_deref().unwrap_or("all"), World::lookup)?;
+    let expacs = parse_filter(expacs.as_deref().unwrap_or("all"), Expac::lookup)?;
     let tx = ctx.data().db.begin().await?;
 
     // Send an "initializing" message first so that we can get its ID
@@ -40,18 +102,30 @@ pub async fn create_dashboard(ctx: Context<'_>) -> eyre::Result<()> {
         id: NotSet,
         channel_id: Set(ctx.channel_id().0 as i64),
         message_id: Set(msg.id.0 as i64),
+        sticky: Set(sticky.unwrap_or(false)),
+        worlds: Set(worlds),
+        expacs: Set(expacs),
+        transposed: Set(transposed.unwrap_or(false)),
+        sort_by_force: Set(sort_by_force.unwrap_or(false)),
+        compact: Set(compact.unwrap_or(false)),
+        data_center: Set(data_center),
+        overview: Set(overview.unwrap_or(false)),
         ..Default::default()
     };
     let dashboard = dashboard.insert(&tx).await?;
-    let trains = train::Entity::find()
-        .filter(train::Column::World.ne(World::Testing))
-        .all(&tx)
-        .await?;
 
     // Commit before updating the message.
     tx.commit().await?;
 
-    refresh_dashboard(ctx, dashboard, trains).await
+    let refreshed = ctx
+        .data()
+        .refresh
+        .refresh([Target::Dashboard(dashboard.id)])
+        .await;
+    if !refreshed {
+        bail!("Unable to initialize dashboard");
+    }
+    Ok(())
 }
 
 /// Add a new hunt train monitor post
@@ -61,9 +135,18 @@ pub async fn create_dashboard(ctx: Context<'_>) -> eyre::Result<()> {
 #[poise::command(slash_command)]
 pub async fn create_monitor(
     ctx: Context<'_>,
-    #[description = "World server"] world: World,
-    #[description = "Expansion"] expac: Expac,
+    #[autocomplete = "autocomplete::train"]
+    #[description = "Train, e.g. \"Seraph EW\", instead of world and expansion"]
+    train: Option<TrainRef>,
+    #[autocomplete = "autocomplete::world"]
+    #[description = "World server"]
+    world: Option<World>,
+    #[autocomplete = "autocomplete::expac"]
+    #[description = "Expansion"]
+    expac: Option<Expac>,
+    #[description = "Repost the monitor if it is deleted (default: no)"] sticky: Option<bool>,
 ) -> eyre::Result<()> {
+    let (world, expac) = TrainRef::select(train, world, expac)?;
     if ctx.guild_id() != Some(ctx.data().train_guild_id) {
         bail!("Not allowed in this guild/in DM");
     }
@@ -85,36 +168,173 @@ pub async fn create_monitor(
         train_id: Set(train.id),
         channel_id: Set(ctx.channel_id().0 as i64),
         message_id: Set(msg.id.0 as i64),
+        sticky: Set(sticky.unwrap_or(false)),
         ..Default::default()
     };
     let monitor = monitor.insert(&tx).await?;
     // Commit before updating the message.
     tx.commit().await?;
 
-    refresh_monitor(ctx, monitor, &train).await?;
+    let refreshed = ctx
+        .data()
+        .refresh
+        .refresh([Target::Monitor(monitor.id)])
+        .await;
+    if !refreshed {
+        bail!("Unable to initialize monitor");
+    }
 
     Ok(())
 }
 
-fn monitor_msg(base: String, success: bool) -> String {
-    if success {
-        format!("{}.", base)
-    } else {
-        format!(
-            "Error: {}, but not all monitor posts could be updated.",
-            base
-        )
+/// Parses a comma-separated list, each item resolved with `lookup`, into the
+/// form stored in the DB, where `None` means no filtering.
+pub(crate) fn parse_filter<T: std::fmt::Display>(
+    input: &str,
+    lookup: fn(&str) -> Result<T, LookupError>,
+) -> eyre::Result<Option<String>> {
+    let input = input.trim();
+    if input.is_empty() || input.eq_ignore_ascii_case("all") {
+        return Ok(None);
+    }
+    let values = input
+        .split(',')
+        .map(|v| lookup(v).map(|v| v.to_string()))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(Some(values.join(",")))
+}
+
+fn message_link(guild_id: GuildId, channel_id: i64, message_id: i64) -> String {
+    MessageId(message_id as u64).link(ChannelId(channel_id as u64), Some(guild_id))
+}
+
+/// Joins `lines` for use in an embed description, eliding whatever does not fit.
+pub(crate) fn list_description(lines: Vec<String>, empty: &str) -> String {
+    // Embed descriptions are limited to 4096 characters; leave room for the note.
+    const LIMIT: usize = 4000;
+    if lines.is_empty() {
+        return empty.to_owned();
+    }
+    let total = lines.len();
+    let mut description = String::new();
+    for (i, line) in lines.into_iter().enumerate() {
+        if description.len() + line.len() + 1 > LIMIT {
+            description.push_str(&format!("…and {} more", total - i));
+            break;
+        }
+        description.push_str(&line);
+        description.push('\n');
+    }
+    description
+}
+
+/// When a train was completed, given either `completion_time` or `force_time`,
+/// if either was typed.
+async fn last_run_time(
+    ctx: Context<'_>,
+    completion_time: Option<argument::Timestamp>,
+    force_time: Option<argument::Timestamp>,
+) -> eyre::Result<Option<DateTime<Utc>>> {
+    Ok(match (completion_time, force_time) {
+        (Some(_), Some(_)) => bail!("Cannot provide both completion_time and force_time"),
+        (Some(completed), _) => Some(completed.resolve(ctx).await?),
+        (_, Some(force)) => Some(force.resolve(ctx).await? - Duration::hours(6)),
+        _ => None,
+    })
+}
+
+/// When the current run of `train` began, if it is running.
+fn run_start(train: &train::Model) -> Option<DateTime<Utc>> {
+    match train.status {
+        Status::Running => train.updated_at,
+        _ => None,
     }
 }
 
+/// When the last mark reported killed during the current run of `train`
+/// died, if it is running.
+async fn run_last_kill(
+    db: &impl ConnectionTrait,
+    train: &train::Model,
+) -> eyre::Result<Option<DateTime<Utc>>> {
+    Ok(match run_start(train) {
+        Some(started) => mark_kill::latest(db, train.world, train.expac, started).await?,
+        None => None,
+    })
+}
+
+/// Marks `train` as complete at `last_run_time`, or without one, when the
+/// last mark reported killed during its run died (default: now), and records
+/// its marks as killed then.
+async fn complete(
+    db: &impl ConnectionTrait,
+    mut train: train::Model,
+    last_run_time: Option<DateTime<Utc>>,
+) -> eyre::Result<train::Model> {
+    let started = run_start(&train);
+    let last_run_time = match last_run_time {
+        Some(last_run_time) => last_run_time,
+        None => run_last_kill(db, &train).await?.unwrap_or_else(Utc::now),
+    };
+    train.done(last_run_time);
+    let train = train::ActiveModel::from(train).update(db).await?;
+    let since = started.unwrap_or(last_run_time);
+    mark_kill::record_train(db, train.world, train.expac, since, last_run_time).await?;
+    Ok(train)
+}
+
+/// Words a reply about `train` the way the train guild has configured, with
+/// any variables specific to the reply added by `extra`.
+async fn announcement(
+    ctx: Context<'_>,
+    key: TemplateKey,
+    train: &train::Model,
+    extra: impl FnOnce(Vars, Locale) -> Vars,
+) -> eyre::Result<String> {
+    let db = &ctx.data().db;
+    let guild_id = ctx.data().train_guild_id.0 as i64;
+    let locale = reply_locale(ctx).await?;
+    let theme = Theme::load(db, guild_id).await?;
+    let templates = Templates::load(db, guild_id, locale).await?;
+    let vars = extra(Vars::train(train, &theme, locale), locale);
+    Ok(templates.render(key, &vars))
+}
+
+/// `base`, a reply to a command, saying whether every monitor post could be updated.
+pub(crate) async fn monitor_msg(
+    ctx: Context<'_>,
+    base: String,
+    success: bool,
+) -> eyre::Result<String> {
+    let locale = reply_locale(ctx).await?;
+    let text = if success {
+        Text::MonitorsUpdated
+    } else {
+        Text::MonitorsNotUpdated
+    };
+    Ok(render(
+        locale.text(text),
+        &Vars::default().with("reply", base),
+    ))
+}
+
 /// Mark a train as scouted
 #[poise::command(slash_command)]
 pub async fn scout(
     ctx: Context<'_>,
-    #[description = "World server"] world: World,
-    #[description = "Expansion"] expac: Expac,
-    #[description = "Link to a map or a message with flag locations (leave blank to clear existing map)"] map_link: Option<String>,
+    #[autocomplete = "autocomplete::train"]
+    #[description = "Train, e.g. \"Seraph EW\", instead of world and expansion"]
+    train: Option<TrainRef>,
+    #[autocomplete = "autocomplete::world"]
+    #[description = "World server"]
+    world: Option<World>,
+    #[autocomplete = "autocomplete::expac"]
+    #[description = "Expansion"]
+    expac: Option<Expac>,
+    #[description = "Link to a map or a message with flag locations (leave blank to clear existing map)"]
+    map_link: Option<String>,
 ) -> eyre::Result<()> {
+    let (world, expac) = TrainRef::select(train, world, expac)?;
     if let Some(ref url) = map_link {
         let _ = url.parse::<::url::Url>()?;
     }
@@ -125,16 +345,17 @@ pub async fn scout(
     let train = train::ActiveModel::from(train).update(db).await?;
 
     ctx.defer().await?;
-    let success = refresh_monitors(ctx, &train).await && refresh_dashboards(ctx).await;
-    let scout_text = match train.scout_map {
-        Some(url) => format!("[scouted]({})", url),
-        None => "scouted".to_owned(),
-    };
-    ctx.say(monitor_msg(
-        format!("{} {} Train has been {}", world, expac, scout_text),
-        success,
-    ))
+    let success = refresh_train(ctx, &train).await;
+    let text = announcement(ctx, TemplateKey::Scouted, &train, |vars, locale| {
+        let scouted = locale.text(Text::Scouted);
+        let scout_text = match &train.scout_map {
+            Some(url) => format!("[{}]({})", scouted, url),
+            None => scouted.to_owned(),
+        };
+        vars.with("scouted", scout_text)
+    })
     .await?;
+    ctx.say(monitor_msg(ctx, text, success).await?).await?;
 
     Ok(())
 }
@@ -143,10 +364,18 @@ pub async fn scout(
 #[poise::command(slash_command)]
 pub async fn start(
     ctx: Context<'_>,
-    #[description = "World server"] world: World,
-    #[description = "Expansion"] expac: Expac,
+    #[autocomplete = "autocomplete::train"]
+    #[description = "Train, e.g. \"Seraph EW\", instead of world and expansion"]
+    train: Option<TrainRef>,
+    #[autocomplete = "autocomplete::world"]
+    #[description = "World server"]
+    world: Option<World>,
+    #[autocomplete = "autocomplete::expac"]
+    #[description = "Expansion"]
+    expac: Option<Expac>,
     #[description = "Link to a map or a message with flag locations"] map_link: Option<String>,
 ) -> eyre::Result<()> {
+    let (world, expac) = TrainRef::select(train, world, expac)?;
     if let Some(ref url) = map_link {
         let _ = url.parse::<::url::Url>()?;
     }
@@ -159,16 +388,17 @@ pub async fn start(
     if map_link.is_some() {
         train.scout_map = map_link;
     }
-    train.start();
+    train.start(ctx.author().id.0 as i64);
     let train = train::ActiveModel::from(train).update(db).await?;
+    let linked = scheduled_train::link_run(db, train.id, Utc::now()).await?;
 
     ctx.defer().await?;
-    let success = refresh_monitors(ctx, &train).await && refresh_dashboards(ctx).await;
-    ctx.say(monitor_msg(
-        format!("{} {} Train is now running", world, expac),
-        success,
-    ))
-    .await?;
+    if let Some(scheduled) = linked {
+        event::start(&ctx.discord().http, ctx.data().train_guild_id, &scheduled).await;
+    }
+    let success = refresh_train(ctx, &train).await;
+    let text = announcement(ctx, TemplateKey::Started, &train, |vars, _| vars).await?;
+    ctx.say(monitor_msg(ctx, text, success).await?).await?;
 
     Ok(())
 }
@@ -177,37 +407,127 @@ pub async fn start(
 #[poise::command(slash_command)]
 pub async fn done(
     ctx: Context<'_>,
-    #[description = "World server"] world: World,
-    #[description = "Expansion"] expac: Expac,
-    #[description = "Discord timestamp when it was finished, defaults to now"]
+    #[autocomplete = "autocomplete::train"]
+    #[description = "Train, e.g. \"Seraph EW\", instead of world and expansion"]
+    train: Option<TrainRef>,
+    #[autocomplete = "autocomplete::world"]
+    #[description = "World server"]
+    world: Option<World>,
+    #[autocomplete = "autocomplete::expac"]
+    #[description = "Expansion"]
+    expac: Option<Expac>,
+    #[description = "When it was finished, e.g. \"10m ago\" or \"21:30\" (default: now)"]
     completion_time: Option<argument::Timestamp>,
-    #[description = "Discord timestamp when it will be forced, mutually exclusive with `completion_time`"]
+    #[description = "When it will be forced, e.g. \"in 5h\"; not with `completion_time`"]
     force_time: Option<argument::Timestamp>,
 ) -> eyre::Result<()> {
-    let last_run_time = match (completion_time, force_time) {
-        (Some(_), Some(_)) => bail!("Cannot provide both completion_time and force_time"),
-        (Some(completed), _) => completed.0,
-        (_, Some(force)) => force.0 - Duration::hours(6),
+    let (world, expac) = TrainRef::select(train, world, expac)?;
+    let last_run_time = last_run_time(ctx, completion_time, force_time).await?;
+
+    let db = &ctx.data().db;
+    let train = train::find_or_create(db, world, expac).await?;
+    let train = complete(db, train, last_run_time).await?;
+
+    ctx.defer().await?;
+    event::complete(&ctx.discord().http, db, ctx.data().train_guild_id, train.id).await?;
+    let success = refresh_train(ctx, &train).await;
+    let text = announcement(ctx, TemplateKey::Completed, &train, |vars, _| vars).await?;
+    ctx.say(monitor_msg(ctx, text, success).await?).await?;
+
+    Ok(())
+}
+
+/// Mark a train's marks as killed outside of a train.
+#[poise::command(slash_command)]
+pub async fn sniped(
+    ctx: Context<'_>,
+    #[autocomplete = "autocomplete::train"]
+    #[description = "Train, e.g. \"Seraph EW\", instead of world and expansion"]
+    train: Option<TrainRef>,
+    #[autocomplete = "autocomplete::world"]
+    #[description = "World server"]
+    world: Option<World>,
+    #[autocomplete = "autocomplete::expac"]
+    #[description = "Expansion"]
+    expac: Option<Expac>,
+    #[description = "When the marks were killed, e.g. \"10m ago\" or \"21:30\" (default: now)"]
+    kill_time: Option<argument::Timestamp>,
+) -> eyre::Result<()> {
+    let (world, expac) = TrainRef::select(train, world, expac)?;
+    let kill_time = match kill_time {
+        Some(kill_time) => kill_time.resolve(ctx).await?,
+        None => Utc::now(),
+    };
+
+    let db = &ctx.data().db;
+    let mut train = train::find_or_create(db, world, expac).await?;
+    train.sniped(kill_time);
+    let train = train::ActiveModel::from(train).update(db).await?;
+    mark_kill::record_train(db, world, expac, kill_time, kill_time).await?;
+
+    ctx.defer().await?;
+    let success = refresh_train(ctx, &train).await;
+    let text = announcement(ctx, TemplateKey::Sniped, &train, |vars, _| vars).await?;
+    ctx.say(monitor_msg(ctx, text, success).await?).await?;
+
+    Ok(())
+}
+
+/// Mark a train as stopped before all of its marks were killed.
+#[poise::command(slash_command)]
+pub async fn abandon(
+    ctx: Context<'_>,
+    #[autocomplete = "autocomplete::train"]
+    #[description = "Train, e.g. \"Seraph EW\", instead of world and expansion"]
+    train: Option<TrainRef>,
+    #[autocomplete = "autocomplete::world"]
+    #[description = "World server"]
+    world: Option<World>,
+    #[autocomplete = "autocomplete::expac"]
+    #[description = "Expansion"]
+    expac: Option<Expac>,
+    #[description = "Marks still alive, if some were killed (default: none were killed)"]
+    #[min = 1]
+    #[max = 20]
+    marks_left: Option<i64>,
+    #[description = "When the last mark was killed, with `marks_left` (default: now)"]
+    kill_time: Option<argument::Timestamp>,
+) -> eyre::Result<()> {
+    let (world, expac) = TrainRef::select(train, world, expac)?;
+    let typed = kill_time.is_some();
+    let mut kill_time = match (marks_left, kill_time) {
+        (None, Some(_)) => bail!("kill_time can only be given with marks_left"),
+        (_, Some(kill_time)) => kill_time.resolve(ctx).await?,
         _ => Utc::now(),
     };
 
     let db = &ctx.data().db;
     let mut train = train::find_or_create(db, world, expac).await?;
-    train.done(last_run_time);
+    // Marks reported killed during the run tell when the last one died.
+    if let (Some(_), false) = (marks_left, typed) {
+        if let Some(latest) = run_last_kill(db, &train).await? {
+            kill_time = latest;
+        }
+    }
+    match marks_left {
+        Some(marks_left) => train.partial(kill_time, marks_left as i32),
+        None => train.abandon(),
+    }
     let train = train::ActiveModel::from(train).update(db).await?;
 
     ctx.defer().await?;
-    let success = refresh_monitors(ctx, &train).await && refresh_dashboards(ctx).await;
-    ctx.say(monitor_msg(
-        format!(
-            "{} {} Train completed at <t:{}:f>",
-            world,
-            expac,
-            last_run_time.timestamp()
-        ),
-        success,
-    ))
-    .await?;
+    event::complete(&ctx.discord().http, db, ctx.data().train_guild_id, train.id).await?;
+    let success = refresh_train(ctx, &train).await;
+    let text = match marks_left {
+        Some(marks_left) => {
+            announcement(ctx, TemplateKey::Partial, &train, |vars, _| {
+                vars.with("marks_left", marks_left.to_string())
+            })
+            .await?
+        }
+        None => announcement(ctx, TemplateKey::Abandoned, &train, |vars, _| vars).await?,
+    };
+    ctx.say(monitor_msg(ctx, text, success).await?).await?;
 
     Ok(())
 }
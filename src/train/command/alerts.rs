@@ -0,0 +1,236 @@
+use eyre::{bail, eyre};
+use poise::serenity_prelude::{Channel, GuildId, Role, RoleId};
+use sea_orm::{ActiveModelTrait, ColumnTrait, EntityTrait, NotSet, QueryFilter, Set};
+
+use super::list_description;
+use crate::command::{reply_locale, Context};
+use entity::force_alert::{self, AlertKind};
+use entity::locale::{Locale, Text};
+use entity::template::{render, Vars};
+
+/// Minutes before the force time that `soon` alerts are posted by default.
+const DEFAULT_MINUTES: i64 = 30;
+
+/// `text` in `locale`, with `vars` filled in.
+fn localized(locale: Locale, text: Text, vars: &Vars) -> String {
+    render(locale.text(text), vars)
+}
+
+/// Fails unless members may be given `role_id` through an alert. Roles that
+/// grant permissions, that an integration manages or that are not below our
+/// own highest role are refused, so that alerts can't hand out privileges.
+async fn check_assignable(
+    ctx: Context<'_>,
+    guild_id: GuildId,
+    role_id: RoleId,
+) -> eyre::Result<()> {
+    let discord = ctx.discord();
+    let guild = guild_id.to_partial_guild(discord).await?;
+    let Some(role) = guild.roles.get(&role_id) else {
+        bail!("The role <@&{}> no longer exists", role_id);
+    };
+    if role.managed {
+        bail!(
+            "{} is managed by an integration, so it can't be given out",
+            role.name
+        );
+    }
+    if !role.permissions.is_empty() {
+        bail!("{} grants permissions, so it can't be given out", role.name);
+    }
+    let me = guild_id
+        .member(discord, discord.cache.current_user_id())
+        .await?;
+    let top = me
+        .roles
+        .iter()
+        .filter_map(|r| guild.roles.get(r))
+        .map(|r| r.position)
+        .max()
+        .unwrap_or(0);
+    if role.position >= top {
+        bail!(
+            "{} is not below my highest role, so I can't give it out",
+            role.name
+        );
+    }
+    Ok(())
+}
+
+/// Alerts for trains that are about to be, or have just been, forced.
+#[poise::command(slash_command, subcommands("set", "remove", "list", "subscribe"))]
+pub async fn alerts(_ctx: Context<'_>) -> eyre::Result<()> {
+    Err(eyre!("unsupported"))
+}
+
+/// Post an alert in this channel before or when trains are forced
+#[poise::command(slash_command, ephemeral, required_permissions = "MANAGE_GUILD")]
+pub async fn set(
+    ctx: Context<'_>,
+    #[description = "Which alert"] kind: AlertKind,
+    #[description = "Role to ping, which members can take with `/train alerts subscribe`"]
+    role: Option<Role>,
+    #[description = "Minutes before the force time, for `soon` alerts (default: 30)"]
+    #[min = 1]
+    #[max = 360]
+    minutes: Option<i64>,
+) -> eyre::Result<()> {
+    let Some(guild_id) = ctx.guild_id().filter(|&g| g == ctx.data().train_guild_id) else {
+        bail!("Not allowed in this guild/in DM");
+    };
+    if let Some(role) = &role {
+        check_assignable(ctx, guild_id, role.id).await?;
+    }
+    let db = &ctx.data().db;
+    let channel_id = ctx.channel_id().0 as i64;
+    let existing = force_alert::Entity::find()
+        .filter(force_alert::Column::ChannelId.eq(channel_id))
+        .filter(force_alert::Column::Kind.eq(kind))
+        .one(db)
+        .await?;
+    let mut alert = match existing {
+        Some(existing) => force_alert::ActiveModel::from(existing),
+        None => force_alert::ActiveModel {
+            id: NotSet,
+            channel_id: Set(channel_id),
+            kind: Set(kind),
+            ..Default::default()
+        },
+    };
+    alert.role_id = Set(role.as_ref().map(|r| r.id.0 as i64));
+    let minutes = minutes.unwrap_or(DEFAULT_MINUTES);
+    alert.minutes = Set(minutes);
+    alert.save(db).await?;
+
+    let locale = reply_locale(ctx).await?;
+    let vars = Vars::default().with("minutes", minutes.to_string());
+    let mut text = match kind {
+        AlertKind::Soon => localized(locale, Text::AlertSetSoon, &vars),
+        AlertKind::Forced => localized(locale, Text::AlertSetForced, &vars),
+    };
+    if let Some(role) = role {
+        let vars = Vars::default().with("role", format!("<@&{}>", role.id));
+        text.push_str(&localized(locale, Text::AlertPing, &vars));
+    }
+    ctx.say(text).await?;
+    Ok(())
+}
+
+/// Stop posting an alert in this channel
+#[poise::command(slash_command, ephemeral, required_permissions = "MANAGE_GUILD")]
+pub async fn remove(
+    ctx: Context<'_>,
+    #[description = "Which alert"] kind: AlertKind,
+) -> eyre::Result<()> {
+    if ctx.guild_id() != Some(ctx.data().train_guild_id) {
+        bail!("Not allowed in this guild/in DM");
+    }
+    let result = force_alert::Entity::delete_many()
+        .filter(force_alert::Column::ChannelId.eq(ctx.channel_id().0 as i64))
+        .filter(force_alert::Column::Kind.eq(kind))
+        .exec(&ctx.data().db)
+        .await?;
+    if result.rows_affected == 0 {
+        bail!("No `{}` alert is posted in this channel", kind);
+    }
+    let locale = reply_locale(ctx).await?;
+    let vars = Vars::default().with("kind", kind.name(locale).to_owned());
+    ctx.say(localized(locale, Text::AlertRemoved, &vars))
+        .await?;
+    Ok(())
+}
+
+/// List the channels that force alerts are posted in
+#[poise::command(slash_command, ephemeral)]
+pub async fn list(ctx: Context<'_>) -> eyre::Result<()> {
+    if ctx.guild_id() != Some(ctx.data().train_guild_id) {
+        bail!("Not allowed in this guild/in DM");
+    }
+    let locale = reply_locale(ctx).await?;
+    let alerts = force_alert::Entity::find().all(&ctx.data().db).await?;
+    let lines = alerts
+        .into_iter()
+        .map(|alert| {
+            let vars = Vars::default()
+                .with("kind", alert.kind.name(locale).to_owned())
+                .with("minutes", alert.minutes.to_string())
+                .with("channel", format!("<#{}>", alert.channel_id));
+            let mut line = match alert.kind {
+                AlertKind::Soon => localized(locale, Text::AlertListSoon, &vars),
+                AlertKind::Forced => localized(locale, Text::AlertListForced, &vars),
+            };
+            if let Some(role_id) = alert.role_id {
+                let vars = Vars::default().with("role", format!("<@&{}>", role_id));
+                line.push_str(&localized(locale, Text::AlertListPing, &vars));
+            }
+            line
+        })
+        .collect();
+    ctx.send(|m| {
+        m.embed(|e| {
+            e.title(locale.text(Text::AlertListTitle))
+                .description(list_description(lines, locale.text(Text::AlertListEmpty)))
+        })
+    })
+    .await?;
+    Ok(())
+}
+
+/// Take or drop the role pinged by an alert
+#[poise::command(slash_command, ephemeral)]
+pub async fn subscribe(
+    ctx: Context<'_>,
+    #[description = "Which alert"] kind: AlertKind,
+    #[description = "Channel of the alert, if there are several"] channel: Option<Channel>,
+    #[description = "Whether to be pinged (default: true)"] subscribed: Option<bool>,
+) -> eyre::Result<()> {
+    let Some(guild_id) = ctx.guild_id().filter(|&g| g == ctx.data().train_guild_id) else {
+        bail!("Not allowed in this guild/in DM");
+    };
+    let subscribed = subscribed.unwrap_or(true);
+    let mut query = force_alert::Entity::find()
+        .filter(force_alert::Column::Kind.eq(kind))
+        .filter(force_alert::Column::RoleId.is_not_null());
+    if let Some(channel) = &channel {
+        query = query.filter(force_alert::Column::ChannelId.eq(channel.id().0 as i64));
+    }
+    let alerts = query.all(&ctx.data().db).await?;
+    let role_id = match alerts[..] {
+        [] => bail!("No `{}` alert there pings a role", kind),
+        [ref alert] => RoleId(alert.role_id.unwrap_or_default() as u64),
+        _ => {
+            let channels: Vec<_> = alerts
+                .iter()
+                .map(|a| format!("<#{}>", a.channel_id))
+                .collect();
+            bail!(
+                "`{}` alerts are posted in {}; pick a channel",
+                kind,
+                channels.join(", ")
+            );
+        }
+    };
+
+    ctx.defer().await?;
+    let http = &ctx.discord().http;
+    let user_id = ctx.author().id.0;
+    let reason = Some("Force alert subscription");
+    if subscribed {
+        // The role may have changed since the alert was set.
+        check_assignable(ctx, guild_id, role_id).await?;
+        http.add_member_role(guild_id.0, user_id, role_id.0, reason)
+            .await?;
+    } else {
+        http.remove_member_role(guild_id.0, user_id, role_id.0, reason)
+            .await?;
+    }
+    let locale = reply_locale(ctx).await?;
+    let vars = Vars::default().with("roles", format!("<@&{}>", role_id));
+    let text = if subscribed {
+        Text::AlertSubscribed
+    } else {
+        Text::AlertUnsubscribed
+    };
+    ctx.say(localized(locale, text, &vars)).await?;
+    Ok(())
+}
@@ -0,0 +1,155 @@
+use chrono::{DateTime, Utc};
+use eyre::eyre;
+use sea_orm::{ActiveModelTrait, Iterable, TransactionTrait};
+
+use super::{complete, last_run_time, monitor_msg};
+use crate::command::{argument, autocomplete, Context};
+use crate::train::{event, refresh_trains};
+use entity::train::{self, Status};
+use entity::{scheduled_train, Expac, World};
+
+/// Update several trains on one world at once.
+#[poise::command(slash_command, subcommands("done", "start", "reset"))]
+pub async fn batch(_ctx: Context<'_>) -> eyre::Result<()> {
+    Err(eyre!("unsupported"))
+}
+
+/// Parses a comma-separated list of expansions, or "all".
+fn parse_expacs(input: &str) -> eyre::Result<Vec<Expac>> {
+    let input = input.trim();
+    if input.is_empty() || input.eq_ignore_ascii_case("all") {
+        return Ok(Expac::iter().collect());
+    }
+    let mut expacs = Vec::new();
+    // Full names contain spaces, so only commas separate expansions.
+    for name in input.split(',').map(str::trim).filter(|n| !n.is_empty()) {
+        let expac = Expac::lookup(name)?;
+        if !expacs.contains(&expac) {
+            expacs.push(expac);
+        }
+    }
+    Ok(expacs)
+}
+
+/// A change made to every train of a batch.
+enum Update {
+    /// Complete the trains, at the given time if one was typed.
+    Done(Option<DateTime<Utc>>),
+    /// Start running the trains, conducted by the given user.
+    Start(i64),
+    Reset,
+}
+
+/// Applies `update` to the trains of `world` in `expacs` in a single
+/// transaction, then refreshes their posts together.
+async fn update_all(
+    ctx: Context<'_>,
+    world: World,
+    expacs: &str,
+    update: Update,
+) -> eyre::Result<(Vec<train::Model>, bool)> {
+    let expacs = parse_expacs(expacs)?;
+    let tx = ctx.data().db.begin().await?;
+    let mut trains = Vec::new();
+    let mut linked = Vec::new();
+    for expac in expacs {
+        let mut train = train::find_or_create(&tx, world, expac).await?;
+        let train = match update {
+            Update::Done(last_run_time) => complete(&tx, train, last_run_time).await?,
+            Update::Start(conductor) => {
+                train.start(conductor);
+                train::ActiveModel::from(train).update(&tx).await?
+            }
+            Update::Reset => {
+                train.reset();
+                train::ActiveModel::from(train).update(&tx).await?
+            }
+        };
+        if train.status == Status::Running {
+            linked.extend(scheduled_train::link_run(&tx, train.id, Utc::now()).await?);
+        }
+        trains.push(train);
+    }
+    tx.commit().await?;
+
+    ctx.defer().await?;
+    let http = &ctx.discord().http;
+    let guild_id = ctx.data().train_guild_id;
+    for scheduled in &linked {
+        event::start(http, guild_id, scheduled).await;
+    }
+    for train in trains.iter().filter(|t| t.status == Status::Waiting) {
+        event::complete(http, &ctx.data().db, guild_id, train.id).await?;
+    }
+    let success = refresh_trains(&ctx.data().db, &ctx.data().refresh, &trains).await;
+    Ok((trains, success))
+}
+
+fn names(trains: &[train::Model]) -> String {
+    let names: Vec<_> = trains.iter().map(|t| t.expac.short_name()).collect();
+    names.join(", ")
+}
+
+/// Mark several trains on a world as complete
+#[poise::command(slash_command)]
+pub async fn done(
+    ctx: Context<'_>,
+    #[autocomplete = "autocomplete::world"]
+    #[description = "World server"]
+    world: World,
+    #[description = "Comma-separated expansions, or \"all\""] expacs: String,
+    #[description = "When they were finished, e.g. \"10m ago\" or \"21:30\" (default: now)"]
+    completion_time: Option<argument::Timestamp>,
+    #[description = "When they will be forced, e.g. \"in 5h\"; not with `completion_time`"]
+    force_time: Option<argument::Timestamp>,
+) -> eyre::Result<()> {
+    let last_run_time = last_run_time(ctx, completion_time, force_time).await?;
+    let (trains, success) = update_all(ctx, world, &expacs, Update::Done(last_run_time)).await?;
+    // Without a typed time, each train finished when its own marks died.
+    let mut times: Vec<_> = trains.iter().filter_map(|t| t.last_run).collect();
+    times.sort_unstable();
+    times.dedup();
+    let times: Vec<_> = times
+        .iter()
+        .map(|t| format!("<t:{}:f>", t.timestamp()))
+        .collect();
+    let text = format!(
+        "{} {} Trains completed at {}",
+        world,
+        names(&trains),
+        times.join(", ")
+    );
+    ctx.say(monitor_msg(ctx, text, success).await?).await?;
+    Ok(())
+}
+
+/// Mark several trains on a world as being run
+#[poise::command(slash_command)]
+pub async fn start(
+    ctx: Context<'_>,
+    #[autocomplete = "autocomplete::world"]
+    #[description = "World server"]
+    world: World,
+    #[description = "Comma-separated expansions, or \"all\""] expacs: String,
+) -> eyre::Result<()> {
+    let conductor = ctx.author().id.0 as i64;
+    let (trains, success) = update_all(ctx, world, &expacs, Update::Start(conductor)).await?;
+    let text = format!("{} {} Trains are now running", world, names(&trains));
+    ctx.say(monitor_msg(ctx, text, success).await?).await?;
+    Ok(())
+}
+
+/// Forget the status and times of several trains on a world
+#[poise::command(slash_command)]
+pub async fn reset(
+    ctx: Context<'_>,
+    #[autocomplete = "autocomplete::world"]
+    #[description = "World server"]
+    world: World,
+    #[description = "Comma-separated expansions, or \"all\""] expacs: String,
+) -> eyre::Result<()> {
+    let (trains, success) = update_all(ctx, world, &expacs, Update::Reset).await?;
+    let text = format!("{} {} Trains have been reset", world, names(&trains));
+    ctx.say(monitor_msg(ctx, text, success).await?).await?;
+    Ok(())
+}
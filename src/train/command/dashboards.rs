@@ -0,0 +1,131 @@
+use eyre::{bail, eyre};
+use poise::serenity_prelude::ChannelId;
+use sea_orm::{ActiveModelTrait, EntityTrait, Set};
+
+use super::{list_description, message_link, parse_filter};
+use crate::command::Context;
+use crate::refresh::{self, Target};
+use entity::{dashboard, Expac, World};
+
+/// Manage existing dashboard posts.
+#[poise::command(slash_command, subcommands("list", "configure", "delete"))]
+pub async fn dashboards(_ctx: Context<'_>) -> eyre::Result<()> {
+    Err(eyre!("unsupported"))
+}
+
+/// List dashboard posts
+#[poise::command(slash_command, ephemeral)]
+pub async fn list(ctx: Context<'_>) -> eyre::Result<()> {
+    let Some(guild_id) = ctx.guild_id().filter(|&g| g == ctx.data().train_guild_id) else {
+        bail!("Not allowed in this guild/in DM");
+    };
+    let dashboards = dashboard::Entity::find().all(&ctx.data().db).await?;
+
+    let lines = dashboards
+        .into_iter()
+        .map(|dashboard| {
+            let mut kind = match dashboard.data_center {
+                Some(dc) => format!(" {}", dc),
+                None => String::new(),
+            };
+            if dashboard.overview {
+                kind.push_str(" overview");
+            }
+            format!(
+                "`#{}`{}{}: {}",
+                dashboard.id,
+                kind,
+                if dashboard.sticky { " (sticky)" } else { "" },
+                message_link(guild_id, dashboard.channel_id, dashboard.message_id),
+            )
+        })
+        .collect();
+    ctx.send(|m| {
+        m.embed(|e| {
+            e.title("Train Dashboards")
+                .description(list_description(lines, "No dashboards found."))
+        })
+    })
+    .await?;
+    Ok(())
+}
+
+/// Change which trains a dashboard shows and how
+#[poise::command(slash_command, ephemeral, required_permissions = "MANAGE_MESSAGES")]
+#[allow(clippy::too_many_arguments)]
+pub async fn configure(
+    ctx: Context<'_>,
+    #[description = "Dashboard ID, as shown by `/train dashboards list`"] id: i64,
+    #[description = "Comma-separated worlds to show, or \"all\""] worlds: Option<String>,
+    #[description = "Comma-separated expansions to show, or \"all\""] expacs: Option<String>,
+    #[description = "Show worlds as rows"] transposed: Option<bool>,
+    #[description = "Order worlds by soonest force time"] sort_by_force: Option<bool>,
+    #[description = "Leave out explanatory text and use short labels"] compact: Option<bool>,
+    #[description = "Repost the dashboard if it is deleted"] sticky: Option<bool>,
+) -> eyre::Result<()> {
+    let Some(guild_id) = ctx.guild_id().filter(|&g| g == ctx.data().train_guild_id) else {
+        bail!("Not allowed in this guild/in DM");
+    };
+    let db = &ctx.data().db;
+    let Some(dashboard) = dashboard::Entity::find_by_id(id).one(db).await? else {
+        bail!("No dashboard with ID {}", id);
+    };
+    let mut dashboard = dashboard::ActiveModel::from(dashboard);
+    if let Some(worlds) = worlds {
+        dashboard.worlds = Set(parse_filter(&worlds, World::lookup)?);
+    }
+    if let Some(expacs) = expacs {
+        dashboard.expacs = Set(parse_filter(&expacs, Expac::lookup)?);
+    }
+    if let Some(transposed) = transposed {
+        dashboard.transposed = Set(transposed);
+    }
+    if let Some(sort_by_force) = sort_by_force {
+        dashboard.sort_by_force = Set(sort_by_force);
+    }
+    if let Some(compact) = compact {
+        dashboard.compact = Set(compact);
+    }
+    if let Some(sticky) = sticky {
+        dashboard.sticky = Set(sticky);
+    }
+    let dashboard = dashboard.update(db).await?;
+
+    let link = message_link(guild_id, dashboard.channel_id, dashboard.message_id);
+    if ctx.data().refresh.refresh([Target::Dashboard(id)]).await {
+        ctx.say(format!("Dashboard `#{}` updated: {}", id, link))
+            .await?;
+    } else {
+        ctx.say(format!(
+            "Error: Dashboard `#{}` was reconfigured, but its post could not be updated: {}",
+            id, link
+        ))
+        .await?;
+    }
+    Ok(())
+}
+
+/// Delete a dashboard post and stop tracking it
+#[poise::command(slash_command, ephemeral, required_permissions = "MANAGE_MESSAGES")]
+pub async fn delete(
+    ctx: Context<'_>,
+    #[description = "Dashboard ID, as shown by `/train dashboards list`"] id: i64,
+) -> eyre::Result<()> {
+    if ctx.guild_id() != Some(ctx.data().train_guild_id) {
+        bail!("Not allowed in this guild/in DM");
+    }
+    let db = &ctx.data().db;
+    let Some(dashboard) = dashboard::Entity::find_by_id(id).one(db).await? else {
+        bail!("No dashboard with ID {}", id);
+    };
+    match ChannelId(dashboard.channel_id as u64)
+        .delete_message(ctx.discord(), dashboard.message_id as u64)
+        .await
+    {
+        Err(e) if !refresh::is_gone(&e) => return Err(e.into()),
+        _ => {}
+    }
+    dashboard::Entity::delete_by_id(id).exec(db).await?;
+    ctx.say(format!("Dashboard `#{}` deleted.", id)).await?;
+    Ok(())
+}
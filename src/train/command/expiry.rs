@@ -0,0 +1,110 @@
+use eyre::{bail, eyre};
+use sea_orm::{ActiveModelTrait, ColumnTrait, EntityTrait, Iterable, NotSet, QueryFilter, Set};
+use std::fmt::Write;
+
+use super::monitor_msg;
+use crate::command::Context;
+use crate::train::refresh_all;
+use entity::expiry_rule::{self, Expiry};
+use entity::train::Status;
+
+/// Choose how long statuses last without an update before showing as stale.
+#[poise::command(slash_command, subcommands("show", "set", "reset"))]
+pub async fn expiry(_ctx: Context<'_>) -> eyre::Result<()> {
+    Err(eyre!("unsupported"))
+}
+
+/// Show how long each status lasts without an update
+#[poise::command(slash_command, ephemeral)]
+pub async fn show(ctx: Context<'_>) -> eyre::Result<()> {
+    let Some(guild_id) = ctx.guild_id().filter(|&g| g == ctx.data().train_guild_id) else {
+        bail!("Not allowed in this guild/in DM");
+    };
+    let expiry = Expiry::load(&ctx.data().db, guild_id.0 as i64).await?;
+    let mut description = String::new();
+    // Unknown trains cannot become stale, so they are not listed.
+    for status in Status::iter().filter(|&s| s != Status::Unknown) {
+        let limit = match expiry.limit(status) {
+            Some(limit) => format!("stale after {} hours", limit.num_hours()),
+            None => "never stale".to_owned(),
+        };
+        writeln!(description, "{}: {}", status, limit).unwrap();
+    }
+    ctx.send(|m| m.embed(|e| e.title("Status Expiry").description(description)))
+        .await?;
+    Ok(())
+}
+
+/// Set how many hours a status lasts without an update
+#[poise::command(slash_command, required_permissions = "MANAGE_GUILD")]
+pub async fn set(
+    ctx: Context<'_>,
+    #[description = "Status to change"] status: Status,
+    #[description = "Hours without an update, or 0 to never expire"]
+    #[min = 0]
+    #[max = 168]
+    hours: i64,
+) -> eyre::Result<()> {
+    let Some(guild_id) = ctx.guild_id().filter(|&g| g == ctx.data().train_guild_id) else {
+        bail!("Not allowed in this guild/in DM");
+    };
+    if status == Status::Unknown {
+        bail!("Unknown trains cannot become stale");
+    }
+
+    let db = &ctx.data().db;
+    let existing = expiry_rule::Entity::find()
+        .filter(expiry_rule::Column::GuildId.eq(guild_id.0 as i64))
+        .filter(expiry_rule::Column::Status.eq(status))
+        .one(db)
+        .await?;
+    let mut row = match existing {
+        Some(row) => expiry_rule::ActiveModel::from(row),
+        None => expiry_rule::ActiveModel {
+            id: NotSet,
+            guild_id: Set(guild_id.0 as i64),
+            status: Set(status),
+            ..Default::default()
+        },
+    };
+    row.hours = Set(hours);
+    row.save(db).await?;
+
+    ctx.defer().await?;
+    let success = refresh_all(ctx).await;
+    let base = match hours {
+        0 => format!("{} trains will no longer become stale", status),
+        _ => format!(
+            "{} trains will become stale after {} hours without an update",
+            status, hours
+        ),
+    };
+    ctx.say(monitor_msg(ctx, base, success).await?).await?;
+    Ok(())
+}
+
+/// Go back to the default expiry for one or all statuses
+#[poise::command(slash_command, required_permissions = "MANAGE_GUILD")]
+pub async fn reset(
+    ctx: Context<'_>,
+    #[description = "Status to reset (default: all)"] status: Option<Status>,
+) -> eyre::Result<()> {
+    let Some(guild_id) = ctx.guild_id().filter(|&g| g == ctx.data().train_guild_id) else {
+        bail!("Not allowed in this guild/in DM");
+    };
+    let mut delete = expiry_rule::Entity::delete_many()
+        .filter(expiry_rule::Column::GuildId.eq(guild_id.0 as i64));
+    if let Some(status) = status {
+        delete = delete.filter(expiry_rule::Column::Status.eq(status));
+    }
+    delete.exec(&ctx.data().db).await?;
+
+    ctx.defer().await?;
+    let success = refresh_all(ctx).await;
+    let base = match status {
+        Some(status) => format!("Expiry for {} reset", status),
+        None => "Expiry reset".to_owned(),
+    };
+    ctx.say(monitor_msg(ctx, base, success).await?).await?;
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use eyre::bail;
+use sea_orm::{ActiveModelTrait, ColumnTrait, EntityTrait, NotSet, QueryFilter, Set};
+
+use super::monitor_msg;
+use crate::command::Context;
+use crate::train::refresh_all;
+use entity::guild_setting;
+use entity::locale::Locale;
+
+/// Set the language of monitors and dashboards
+///
+/// Replies to commands use the language of whoever runs them, if available.
+#[poise::command(slash_command, required_permissions = "MANAGE_GUILD")]
+pub async fn language(
+    ctx: Context<'_>,
+    #[description = "Language for monitors and dashboards"] language: Locale,
+) -> eyre::Result<()> {
+    let Some(guild_id) = ctx.guild_id().filter(|&g| g == ctx.data().train_guild_id) else {
+        bail!("Not allowed in this guild/in DM");
+    };
+    let db = &ctx.data().db;
+    let existing = guild_setting::Entity::find()
+        .filter(guild_setting::Column::GuildId.eq(guild_id.0 as i64))
+        .one(db)
+        .await?;
+    let mut settings = match existing {
+        Some(row) => guild_setting::ActiveModel::from(row),
+        None => guild_setting::ActiveModel {
+            id: NotSet,
+            guild_id: Set(guild_id.0 as i64),
+            locale: NotSet,
+        },
+    };
+    settings.locale = Set(language);
+    settings.save(db).await?;
+
+    ctx.defer().await?;
+    let success = refresh_all(ctx).await;
+    ctx.say(monitor_msg(ctx, format!("Language set to {}", language), success).await?)
+        .await?;
+    Ok(())
+}
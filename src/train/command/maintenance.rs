@@ -0,0 +1,51 @@
+use chrono::Utc;
+use eyre::{bail, eyre};
+
+use super::monitor_msg;
+use crate::command::{argument, Context};
+use crate::train;
+
+/// Track server maintenance, after which every mark respawns.
+#[poise::command(slash_command, subcommands("start", "end"))]
+pub async fn maintenance(_ctx: Context<'_>) -> eyre::Result<()> {
+    Err(eyre!("unsupported"))
+}
+
+/// Mark every train as waiting on the post-maintenance spawn
+#[poise::command(slash_command, required_permissions = "MANAGE_MESSAGES")]
+pub async fn start(ctx: Context<'_>) -> eyre::Result<()> {
+    if ctx.guild_id() != Some(ctx.data().train_guild_id) {
+        bail!("Not allowed in this guild/in DM");
+    }
+    ctx.defer().await?;
+    let (trains, success) = train::start_maintenance(&ctx.data().db, &ctx.data().refresh).await?;
+    let text = format!("Maintenance started; {} trains reset", trains.len());
+    ctx.say(monitor_msg(ctx, text, success).await?).await?;
+    Ok(())
+}
+
+/// Set the spawn time of every train waiting on maintenance
+#[poise::command(slash_command, required_permissions = "MANAGE_MESSAGES")]
+pub async fn end(
+    ctx: Context<'_>,
+    #[description = "When the servers came back up, e.g. \"10m ago\" or \"08:00\" (default: now)"]
+    end_time: Option<argument::Timestamp>,
+) -> eyre::Result<()> {
+    if ctx.guild_id() != Some(ctx.data().train_guild_id) {
+        bail!("Not allowed in this guild/in DM");
+    }
+    let end_time = match end_time {
+        Some(end) => end.resolve(ctx).await?,
+        None => Utc::now(),
+    };
+    ctx.defer().await?;
+    let (trains, success) =
+        train::end_maintenance(&ctx.data().db, &ctx.data().refresh, end_time).await?;
+    let text = format!(
+        "Maintenance ended at <t:{}:f>; {} trains spawn then",
+        end_time.timestamp(),
+        trains.len()
+    );
+    ctx.say(monitor_msg(ctx, text, success).await?).await?;
+    Ok(())
+}
@@ -0,0 +1,133 @@
+use eyre::{bail, eyre};
+use poise::serenity_prelude::ChannelId;
+use sea_orm::{ActiveModelTrait, ColumnTrait, EntityTrait, QueryFilter, Set};
+
+use super::{list_description, message_link};
+use crate::command::argument::TrainRef;
+use crate::command::{autocomplete, Context};
+use crate::refresh::{self, Target};
+use entity::{monitor, train, Expac, World};
+
+/// Manage existing monitor posts.
+#[poise::command(slash_command, subcommands("list", "delete", "retarget"))]
+pub async fn monitors(_ctx: Context<'_>) -> eyre::Result<()> {
+    Err(eyre!("unsupported"))
+}
+
+/// List monitor posts, optionally only those for one world or expansion
+#[poise::command(slash_command, ephemeral)]
+pub async fn list(
+    ctx: Context<'_>,
+    #[autocomplete = "autocomplete::world"]
+    #[description = "Only show monitors for this world"]
+    world: Option<World>,
+    #[autocomplete = "autocomplete::expac"]
+    #[description = "Only show monitors for this expansion"]
+    expac: Option<Expac>,
+) -> eyre::Result<()> {
+    let Some(guild_id) = ctx.guild_id().filter(|&g| g == ctx.data().train_guild_id) else {
+        bail!("Not allowed in this guild/in DM");
+    };
+    let mut query = monitor::Entity::find().find_also_related(train::Entity);
+    if let Some(world) = world {
+        query = query.filter(train::Column::World.eq(world));
+    }
+    if let Some(expac) = expac {
+        query = query.filter(train::Column::Expac.eq(expac));
+    }
+    let monitors = query.all(&ctx.data().db).await?;
+
+    let lines = monitors
+        .into_iter()
+        .filter_map(|(monitor, train)| {
+            let train = train?;
+            Some(format!(
+                "`#{}` {} {} Train{}: {}",
+                monitor.id,
+                train.world,
+                train.expac,
+                if monitor.sticky { " (sticky)" } else { "" },
+                message_link(guild_id, monitor.channel_id, monitor.message_id),
+            ))
+        })
+        .collect();
+    ctx.send(|m| {
+        m.embed(|e| {
+            e.title("Train Monitors")
+                .description(list_description(lines, "No monitors found."))
+        })
+    })
+    .await?;
+    Ok(())
+}
+
+/// Delete a monitor post and stop tracking it
+#[poise::command(slash_command, ephemeral, required_permissions = "MANAGE_MESSAGES")]
+pub async fn delete(
+    ctx: Context<'_>,
+    #[description = "Monitor ID, as shown by `/train monitors list`"] id: i64,
+) -> eyre::Result<()> {
+    if ctx.guild_id() != Some(ctx.data().train_guild_id) {
+        bail!("Not allowed in this guild/in DM");
+    }
+    let db = &ctx.data().db;
+    let Some(monitor) = monitor::Entity::find_by_id(id).one(db).await? else {
+        bail!("No monitor with ID {}", id);
+    };
+    match ChannelId(monitor.channel_id as u64)
+        .delete_message(ctx.discord(), monitor.message_id as u64)
+        .await
+    {
+        Err(e) if !refresh::is_gone(&e) => return Err(e.into()),
+        _ => {}
+    }
+    monitor::Entity::delete_by_id(id).exec(db).await?;
+    ctx.say(format!("Monitor `#{}` deleted.", id)).await?;
+    Ok(())
+}
+
+/// Point an existing monitor post at a different train
+#[poise::command(slash_command, ephemeral, required_permissions = "MANAGE_MESSAGES")]
+pub async fn retarget(
+    ctx: Context<'_>,
+    #[description = "Monitor ID, as shown by `/train monitors list`"] id: i64,
+    #[autocomplete = "autocomplete::train"]
+    #[description = "Train, e.g. \"Seraph EW\", instead of world and expansion"]
+    train: Option<TrainRef>,
+    #[autocomplete = "autocomplete::world"]
+    #[description = "World server"]
+    world: Option<World>,
+    #[autocomplete = "autocomplete::expac"]
+    #[description = "Expansion"]
+    expac: Option<Expac>,
+) -> eyre::Result<()> {
+    let (world, expac) = TrainRef::select(train, world, expac)?;
+    let Some(guild_id) = ctx.guild_id().filter(|&g| g == ctx.data().train_guild_id) else {
+        bail!("Not allowed in this guild/in DM");
+    };
+    let db = &ctx.data().db;
+    let Some(monitor) = monitor::Entity::find_by_id(id).one(db).await? else {
+        bail!("No monitor with ID {}", id);
+    };
+    let train = train::find_or_create(db, world, expac).await?;
+    let mut monitor = monitor::ActiveModel::from(monitor);
+    monitor.train_id = Set(train.id);
+    monitor.content_hash = Set(None);
+    let monitor = monitor.update(db).await?;
+
+    let link = message_link(guild_id, monitor.channel_id, monitor.message_id);
+    if ctx.data().refresh.refresh([Target::Monitor(id)]).await {
+        ctx.say(format!(
+            "Monitor `#{}` now shows the {} {} Train: {}",
+            id, world, expac, link
+        ))
+        .await?;
+    } else {
+        ctx.say(format!(
+            "Error: Monitor `#{}` now tracks the {} {} Train, but its post could not be updated: {}",
+            id, world, expac, link
+        ))
+        .await?;
+    }
+    Ok(())
+}
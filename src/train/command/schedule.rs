@@ -0,0 +1,324 @@
+use chrono::Utc;
+use eyre::{bail, eyre};
+use poise::serenity_prelude::{Permissions, User};
+use sea_orm::{ActiveModelTrait, EntityTrait, NotSet, QueryOrder, Set};
+
+use super::{list_description, monitor_msg};
+use crate::command::argument::{self, TrainRef};
+use crate::command::{autocomplete, Context};
+use crate::train::{event, plan, refresh_train};
+use entity::{recurring_train, scheduled_train, train, Expac, World};
+
+/// Longest accepted notes for a planned train.
+const MAX_NOTES_LEN: usize = 200;
+
+/// Plan trains ahead of time.
+#[poise::command(slash_command, subcommands("add", "cancel", "pause", "list"))]
+pub async fn schedule(_ctx: Context<'_>) -> eyre::Result<()> {
+    Err(eyre!("unsupported"))
+}
+
+/// Whether the author may change plans made for `conductor`: only the
+/// conductor and moderators may.
+async fn may_manage(ctx: Context<'_>, conductor: i64) -> bool {
+    if conductor == ctx.author().id.0 as i64 {
+        return true;
+    }
+    ctx.author_member().await.is_some_and(|member| {
+        member
+            .permissions
+            .is_some_and(|p| p.contains(Permissions::MANAGE_MESSAGES))
+    })
+}
+
+/// Plan a train, shown on its monitors and dashboards until it is started
+#[poise::command(slash_command)]
+#[allow(clippy::too_many_arguments)]
+pub async fn add(
+    ctx: Context<'_>,
+    #[description = "When the train will start, e.g. \"21:30\" or \"in 2h\""]
+    time: argument::Timestamp,
+    #[autocomplete = "autocomplete::train"]
+    #[description = "Train, e.g. \"Seraph EW\", instead of world and expansion"]
+    train: Option<TrainRef>,
+    #[autocomplete = "autocomplete::world"]
+    #[description = "World server"]
+    world: Option<World>,
+    #[autocomplete = "autocomplete::expac"]
+    #[description = "Expansion"]
+    expac: Option<Expac>,
+    #[description = "Who will conduct the train (default: you)"] conductor: Option<User>,
+    #[description = "Anything passengers should know"] notes: Option<String>,
+    #[description = "Minutes before the start to post a reminder here (default: 15)"]
+    #[min = 0]
+    #[max = 1440]
+    remind: Option<i64>,
+    #[description = "Where the train starts, shown on its Discord event"] location: Option<String>,
+    #[description = "Run the train again every this many weeks"]
+    #[min = 1]
+    #[max = 4]
+    repeat_weeks: Option<i64>,
+) -> eyre::Result<()> {
+    let (world, expac) = TrainRef::select(train, world, expac)?;
+    if ctx.guild_id() != Some(ctx.data().train_guild_id) {
+        bail!("Not allowed in this guild/in DM");
+    }
+    let start_time = time.resolve_after(ctx, Utc::now()).await?;
+    if start_time <= Utc::now() {
+        bail!("Planned trains must start in the future");
+    }
+    let notes = notes.map(|n| n.trim().to_owned()).filter(|n| !n.is_empty());
+    if notes
+        .as_ref()
+        .is_some_and(|n| n.chars().count() > MAX_NOTES_LEN)
+    {
+        bail!("Notes are too long (at most {} characters)", MAX_NOTES_LEN);
+    }
+    let conductor = conductor.as_ref().unwrap_or_else(|| ctx.author());
+    let remind_minutes = remind.unwrap_or(15);
+    let channel_id = ctx.channel_id().0 as i64;
+
+    ctx.defer().await?;
+    let db = &ctx.data().db;
+    let http = &ctx.discord().http;
+    let guild_id = ctx.data().train_guild_id;
+    let train = train::find_or_create(db, world, expac).await?;
+    let text = match repeat_weeks {
+        None => {
+            let scheduled = scheduled_train::ActiveModel {
+                id: NotSet,
+                train_id: Set(train.id),
+                start_time: Set(start_time),
+                conductor: Set(conductor.id.0 as i64),
+                notes: Set(notes),
+                remind_minutes: Set(remind_minutes),
+                channel_id: Set(channel_id),
+                reminded: Set(false),
+                started_at: Set(None),
+                event_id: Set(None),
+                series_id: Set(None),
+            };
+            let location = location.as_deref();
+            let scheduled = plan::create(
+                http,
+                db,
+                guild_id,
+                &train,
+                scheduled,
+                &conductor.name,
+                location,
+            )
+            .await?;
+            format!(
+                "{} {} Train `#{}` planned for <t:{}:f> with <@{}> conducting",
+                world,
+                expac,
+                scheduled.id,
+                start_time.timestamp(),
+                scheduled.conductor
+            )
+        }
+        Some(weeks) => {
+            let series = recurring_train::ActiveModel {
+                id: NotSet,
+                train_id: Set(train.id),
+                start_time: Set(start_time),
+                interval_weeks: Set(weeks),
+                conductor: Set(conductor.id.0 as i64),
+                notes: Set(notes),
+                remind_minutes: Set(remind_minutes),
+                channel_id: Set(channel_id),
+                location: Set(location),
+                paused: Set(false),
+                planned_until: Set(None),
+            }
+            .insert(db)
+            .await?;
+            let id = series.id;
+            let planned = plan::generate(http, db, guild_id, series, Utc::now()).await?;
+            let mut text = format!(
+                "{} {} Train series `#{}` planned every {} from <t:{}:f> with <@{}> conducting",
+                world,
+                expac,
+                id,
+                weeks_text(weeks),
+                start_time.timestamp(),
+                conductor.id
+            );
+            if !planned.is_empty() {
+                let ids: Vec<_> = planned.iter().map(|s| format!("`#{}`", s.id)).collect();
+                text.push_str(&format!("; planned so far: {}", ids.join(", ")));
+            }
+            text
+        }
+    };
+
+    let success = refresh_train(ctx, &train).await;
+    ctx.say(monitor_msg(ctx, text, success).await?).await?;
+    Ok(())
+}
+
+fn weeks_text(weeks: i64) -> String {
+    if weeks == 1 {
+        "week".to_owned()
+    } else {
+        format!("{} weeks", weeks)
+    }
+}
+
+/// Drop a planned train, or skip one occurrence of a recurring train
+#[poise::command(slash_command)]
+pub async fn cancel(
+    ctx: Context<'_>,
+    #[description = "Planned train ID, as shown by `/train schedule list`"] id: i64,
+) -> eyre::Result<()> {
+    if ctx.guild_id() != Some(ctx.data().train_guild_id) {
+        bail!("Not allowed in this guild/in DM");
+    }
+    let db = &ctx.data().db;
+    let Some((scheduled, Some(train))) = scheduled_train::Entity::find_by_id(id)
+        .find_also_related(train::Entity)
+        .one(db)
+        .await?
+    else {
+        bail!("No planned train with ID {}", id);
+    };
+    if !may_manage(ctx, scheduled.conductor).await {
+        bail!("Only the conductor can cancel planned train `#{}`", id);
+    }
+    if scheduled.started_at.is_some() {
+        bail!("Planned train `#{}` has already started", id);
+    }
+    scheduled_train::Entity::delete_by_id(id).exec(db).await?;
+
+    ctx.defer().await?;
+    // Occurrences are only planned once, so this skips just this one.
+    event::cancel(&ctx.discord().http, ctx.data().train_guild_id, &scheduled).await;
+    let success = refresh_train(ctx, &train).await;
+    let what = if scheduled.series_id.is_some() {
+        "skipped"
+    } else {
+        "cancelled"
+    };
+    let text = format!("{} {} Train `#{}` {}", train.world, train.expac, id, what);
+    ctx.say(monitor_msg(ctx, text, success).await?).await?;
+    Ok(())
+}
+
+/// Stop or resume planning a recurring train
+#[poise::command(slash_command)]
+pub async fn pause(
+    ctx: Context<'_>,
+    #[description = "Series ID, as shown by `/train schedule list`"] series: i64,
+    #[description = "Whether to stop planning occurrences (default: true)"] paused: Option<bool>,
+) -> eyre::Result<()> {
+    if ctx.guild_id() != Some(ctx.data().train_guild_id) {
+        bail!("Not allowed in this guild/in DM");
+    }
+    let paused = paused.unwrap_or(true);
+    let db = &ctx.data().db;
+    let Some((rule, Some(train))) = recurring_train::Entity::find_by_id(series)
+        .find_also_related(train::Entity)
+        .one(db)
+        .await?
+    else {
+        bail!("No recurring train with ID {}", series);
+    };
+    if !may_manage(ctx, rule.conductor).await {
+        bail!("Only the conductor can pause train series `#{}`", series);
+    }
+
+    ctx.defer().await?;
+    let http = &ctx.discord().http;
+    let guild_id = ctx.data().train_guild_id;
+    let now = Utc::now();
+    let mut active = recurring_train::ActiveModel::from(rule);
+    active.paused = Set(paused);
+    if paused {
+        // Planning resumes from whenever the series is resumed.
+        active.planned_until = Set(Some(now));
+    }
+    let rule = active.update(db).await?;
+    if paused {
+        plan::drop_pending(http, db, guild_id, rule.id).await?;
+    } else {
+        plan::generate(http, db, guild_id, rule, now).await?;
+    }
+
+    let success = refresh_train(ctx, &train).await;
+    let what = if paused { "paused" } else { "resumed" };
+    let text = format!(
+        "{} {} Train series `#{}` {}",
+        train.world, train.expac, series, what
+    );
+    ctx.say(monitor_msg(ctx, text, success).await?).await?;
+    Ok(())
+}
+
+/// List recurring and upcoming planned trains
+#[poise::command(slash_command, ephemeral)]
+pub async fn list(ctx: Context<'_>) -> eyre::Result<()> {
+    if ctx.guild_id() != Some(ctx.data().train_guild_id) {
+        bail!("Not allowed in this guild/in DM");
+    }
+    let db = &ctx.data().db;
+    let rules = recurring_train::Entity::find()
+        .find_also_related(train::Entity)
+        .order_by_asc(recurring_train::Column::Id)
+        .all(db)
+        .await?;
+    let upcoming = scheduled_train::upcoming(Utc::now())
+        .find_also_related(train::Entity)
+        .all(db)
+        .await?;
+
+    let rule_lines = rules
+        .into_iter()
+        .filter_map(|(rule, train)| {
+            let train = train?;
+            let mut line = format!(
+                "`#{}` {} {} Train every {}, {} ST, <@{}>",
+                rule.id,
+                train.world,
+                train.expac,
+                weeks_text(rule.interval_weeks),
+                rule.start_time.format("%A %H:%M"),
+                rule.conductor,
+            );
+            if rule.paused {
+                line.push_str(" (paused)");
+            }
+            Some(line)
+        })
+        .collect();
+    let upcoming_lines = upcoming
+        .into_iter()
+        .filter_map(|(scheduled, train)| {
+            let train = train?;
+            let mut line = format!(
+                "`#{}` <t:{}:f> {} {} Train, <@{}>",
+                scheduled.id,
+                scheduled.start_time.timestamp(),
+                train.world,
+                train.expac,
+                scheduled.conductor,
+            );
+            if let Some(series) = scheduled.series_id {
+                line.push_str(&format!(" (series `#{}`)", series));
+            }
+            Some(line)
+        })
+        .collect();
+    ctx.send(|m| {
+        m.embed(|e| {
+            e.title("Recurring Trains")
+                .description(list_description(rule_lines, "No recurring trains."))
+        })
+        .embed(|e| {
+            e.title("Planned Trains")
+                .description(list_description(upcoming_lines, "No trains planned."))
+        })
+    })
+    .await?;
+    Ok(())
+}
@@ -0,0 +1,121 @@
+use eyre::{bail, eyre};
+use sea_orm::Iterable;
+use sea_orm::{ActiveModelTrait, ColumnTrait, EntityTrait, NotSet, QueryFilter, Set};
+
+use super::monitor_msg;
+use crate::command::Context;
+use crate::train::refresh_all;
+use entity::locale::Locale;
+use entity::template::{self, TemplateKey, Templates};
+
+/// Customize the wording of monitors, dashboards and replies.
+#[poise::command(slash_command, subcommands("list", "set", "reset"))]
+pub async fn template(_ctx: Context<'_>) -> eyre::Result<()> {
+    Err(eyre!("unsupported"))
+}
+
+/// Show every template and the variables it can use
+#[poise::command(slash_command, ephemeral)]
+pub async fn list(ctx: Context<'_>) -> eyre::Result<()> {
+    let Some(guild_id) = ctx.guild_id().filter(|&g| g == ctx.data().train_guild_id) else {
+        bail!("Not allowed in this guild/in DM");
+    };
+    let db = &ctx.data().db;
+    let locale = Locale::for_guild(db, guild_id.0 as i64).await?;
+    let templates = Templates::load(db, guild_id.0 as i64, locale).await?;
+    let fields: Vec<_> = TemplateKey::iter()
+        .map(|key| {
+            let name = if templates.is_custom(key) {
+                format!("{} (custom)", key)
+            } else {
+                key.to_string()
+            };
+            let variables = if key.variables().is_empty() {
+                "none".to_owned()
+            } else {
+                key.variables()
+                    .iter()
+                    .map(|v| format!("`{{{}}}`", v))
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            };
+            let value = format!(
+                "{}\n```{}```Variables: {}",
+                key.description(),
+                templates.get(key),
+                variables
+            );
+            (name, value, false)
+        })
+        .collect();
+    ctx.send(|m| m.embed(|e| e.title("Templates").fields(fields)))
+        .await?;
+    Ok(())
+}
+
+/// Change the wording of a template
+///
+/// Variables are written as `{name}`; use `{{` and `}}` for literal braces.
+#[poise::command(slash_command, required_permissions = "MANAGE_GUILD")]
+pub async fn set(
+    ctx: Context<'_>,
+    #[description = "Template to change"] key: TemplateKey,
+    #[description = "New text, with variables such as {world}"] text: String,
+) -> eyre::Result<()> {
+    let Some(guild_id) = ctx.guild_id().filter(|&g| g == ctx.data().train_guild_id) else {
+        bail!("Not allowed in this guild/in DM");
+    };
+    if let Err(e) = key.validate(&text) {
+        bail!("{}", e);
+    }
+
+    let db = &ctx.data().db;
+    let existing = template::Entity::find()
+        .filter(template::Column::GuildId.eq(guild_id.0 as i64))
+        .filter(template::Column::Key.eq(key))
+        .one(db)
+        .await?;
+    let mut row = match existing {
+        Some(row) => template::ActiveModel::from(row),
+        None => template::ActiveModel {
+            id: NotSet,
+            guild_id: Set(guild_id.0 as i64),
+            key: Set(key),
+            text: NotSet,
+        },
+    };
+    row.text = Set(text);
+    row.save(db).await?;
+
+    ctx.defer().await?;
+    let success = refresh_all(ctx).await;
+    ctx.say(monitor_msg(ctx, format!("Template {} updated", key), success).await?)
+        .await?;
+    Ok(())
+}
+
+/// Go back to the default wording for one or all templates
+#[poise::command(slash_command, required_permissions = "MANAGE_GUILD")]
+pub async fn reset(
+    ctx: Context<'_>,
+    #[description = "Template to reset (default: all)"] key: Option<TemplateKey>,
+) -> eyre::Result<()> {
+    let Some(guild_id) = ctx.guild_id().filter(|&g| g == ctx.data().train_guild_id) else {
+        bail!("Not allowed in this guild/in DM");
+    };
+    let mut delete =
+        template::Entity::delete_many().filter(template::Column::GuildId.eq(guild_id.0 as i64));
+    if let Some(key) = key {
+        delete = delete.filter(template::Column::Key.eq(key));
+    }
+    delete.exec(&ctx.data().db).await?;
+
+    ctx.defer().await?;
+    let success = refresh_all(ctx).await;
+    let base = match key {
+        Some(key) => format!("Template {} reset", key),
+        None => "Templates reset".to_owned(),
+    };
+    ctx.say(monitor_msg(ctx, base, success).await?).await?;
+    Ok(())
+}
@@ -0,0 +1,152 @@
+use eyre::{bail, eyre};
+use sea_orm::{ActiveModelTrait, ColumnTrait, EntityTrait, NotSet, QueryFilter, Set};
+use std::fmt::Write;
+
+use super::monitor_msg;
+use crate::command::{reply_locale, Context};
+use crate::train::refresh_all;
+use entity::locale::Text;
+use entity::template::{render, Vars};
+use entity::{theme, train::Status};
+
+/// Customize how train statuses are displayed.
+#[poise::command(slash_command, subcommands("show", "set", "reset"))]
+pub async fn theme(_ctx: Context<'_>) -> eyre::Result<()> {
+    Err(eyre!("unsupported"))
+}
+
+fn parse_colour(input: &str) -> eyre::Result<i32> {
+    let hex = input.trim().trim_start_matches('#');
+    match u32::from_str_radix(hex, 16) {
+        Ok(colour) if hex.len() == 6 => Ok(colour as i32),
+        _ => bail!("Expected a colour like #FF8800, got {}", input),
+    }
+}
+
+/// Show the emoji and colour used for each status
+#[poise::command(slash_command, ephemeral)]
+pub async fn show(ctx: Context<'_>) -> eyre::Result<()> {
+    let Some(guild_id) = ctx.guild_id().filter(|&g| g == ctx.data().train_guild_id) else {
+        bail!("Not allowed in this guild/in DM");
+    };
+    let theme = theme::Theme::load(&ctx.data().db, guild_id.0 as i64).await?;
+    let locale = reply_locale(ctx).await?;
+    let mut description = String::new();
+    for status in [
+        Status::Unknown,
+        Status::Waiting,
+        Status::Scouted,
+        Status::Running,
+        Status::Maintenance,
+        Status::Sniped,
+        Status::Partial,
+        Status::Abandoned,
+    ] {
+        let colour = match theme.colour(status) {
+            Some(colour) => format!("#{}", colour.hex()),
+            None => locale.text(Text::ThemeDefaultColour).to_owned(),
+        };
+        writeln!(
+            description,
+            "{} {}: {}",
+            theme.emoji(status),
+            locale.status(status),
+            colour
+        )
+        .unwrap();
+    }
+    ctx.send(|m| {
+        m.embed(|e| {
+            e.title(locale.text(Text::ThemeTitle))
+                .description(description)
+        })
+    })
+    .await?;
+    Ok(())
+}
+
+/// Set the emoji and/or embed colour for a status
+#[poise::command(slash_command, required_permissions = "MANAGE_GUILD")]
+pub async fn set(
+    ctx: Context<'_>,
+    #[description = "Status to change"] status: Status,
+    #[description = "Unicode or server emoji"] emoji: Option<String>,
+    #[description = "Embed colour, e.g. #FF8800"] colour: Option<String>,
+) -> eyre::Result<()> {
+    let Some(guild_id) = ctx.guild_id().filter(|&g| g == ctx.data().train_guild_id) else {
+        bail!("Not allowed in this guild/in DM");
+    };
+    if emoji.is_none() && colour.is_none() {
+        bail!("Provide an emoji, a colour or both");
+    }
+    let emoji = emoji.map(|e| e.trim().to_owned());
+    if emoji
+        .as_ref()
+        .is_some_and(|e| e.is_empty() || e.len() > 100)
+    {
+        bail!("That doesn't look like an emoji");
+    }
+    let colour = colour.as_deref().map(parse_colour).transpose()?;
+
+    let db = &ctx.data().db;
+    let existing = theme::Entity::find()
+        .filter(theme::Column::GuildId.eq(guild_id.0 as i64))
+        .filter(theme::Column::Status.eq(status))
+        .one(db)
+        .await?;
+    let mut row = match existing {
+        Some(row) => theme::ActiveModel::from(row),
+        None => theme::ActiveModel {
+            id: NotSet,
+            guild_id: Set(guild_id.0 as i64),
+            status: Set(status),
+            emoji: Set(None),
+            colour: Set(None),
+        },
+    };
+    if emoji.is_some() {
+        row.emoji = Set(emoji);
+    }
+    if colour.is_some() {
+        row.colour = Set(colour);
+    }
+    row.save(db).await?;
+
+    ctx.defer().await?;
+    let success = refresh_all(ctx).await;
+    let locale = reply_locale(ctx).await?;
+    let vars = Vars::default().with("status", locale.status(status).to_owned());
+    let text = render(locale.text(Text::ThemeUpdated), &vars);
+    ctx.say(monitor_msg(ctx, text, success).await?).await?;
+    Ok(())
+}
+
+/// Go back to the default emoji and colour for one or all statuses
+#[poise::command(slash_command, required_permissions = "MANAGE_GUILD")]
+pub async fn reset(
+    ctx: Context<'_>,
+    #[description = "Status to reset (default: all)"] status: Option<Status>,
+) -> eyre::Result<()> {
+    let Some(guild_id) = ctx.guild_id().filter(|&g| g == ctx.data().train_guild_id) else {
+        bail!("Not allowed in this guild/in DM");
+    };
+    let mut delete =
+        theme::Entity::delete_many().filter(theme::Column::GuildId.eq(guild_id.0 as i64));
+    if let Some(status) = status {
+        delete = delete.filter(theme::Column::Status.eq(status));
+    }
+    delete.exec(&ctx.data().db).await?;
+
+    ctx.defer().await?;
+    let success = refresh_all(ctx).await;
+    let locale = reply_locale(ctx).await?;
+    let base = match status {
+        Some(status) => {
+            let vars = Vars::default().with("status", locale.status(status).to_owned());
+            render(locale.text(Text::ThemeStatusReset), &vars)
+        }
+        None => locale.text(Text::ThemeReset).to_owned(),
+    };
+    ctx.say(monitor_msg(ctx, base, success).await?).await?;
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use eyre::bail;
+use sea_orm::{ActiveModelTrait, ColumnTrait, EntityTrait, NotSet, QueryFilter, Set};
+
+use crate::command::argument::parse_zone;
+use crate::command::Context;
+use entity::user_setting;
+
+/// Set the time zone for times you enter without one
+///
+/// Times such as "21:30" are taken to be in Server Time (UTC) unless you save
+/// another zone. Abbreviations name a fixed offset, so switch between e.g. EST
+/// and EDT when daylight saving time starts or ends.
+#[poise::command(slash_command, ephemeral)]
+pub async fn timezone(
+    ctx: Context<'_>,
+    #[description = "Abbreviation or offset, e.g. EST, CET or UTC+2 (default: show current)"]
+    zone: Option<String>,
+) -> eyre::Result<()> {
+    let db = &ctx.data().db;
+    let user_id = ctx.author().id.0 as i64;
+    let existing = user_setting::Entity::find()
+        .filter(user_setting::Column::UserId.eq(user_id))
+        .one(db)
+        .await?;
+    let Some(zone) = zone else {
+        let current = existing.and_then(|s| s.timezone);
+        ctx.say(format!(
+            "Times you enter are in {}.",
+            current.as_deref().unwrap_or("Server Time (UTC)")
+        ))
+        .await?;
+        return Ok(());
+    };
+
+    let zone = zone.trim().to_uppercase();
+    if parse_zone(&zone).is_none() {
+        bail!(
+            "Unknown time zone \"{}\". Use an abbreviation such as EST or CET, or an offset such as UTC+2.",
+            zone
+        );
+    }
+    let mut settings = match existing {
+        Some(row) => user_setting::ActiveModel::from(row),
+        None => user_setting::ActiveModel {
+            id: NotSet,
+            user_id: Set(user_id),
+            timezone: NotSet,
+        },
+    };
+    settings.timezone = Set(Some(zone.clone()));
+    settings.save(db).await?;
+    ctx.say(format!("Times you enter are now in {}.", zone))
+        .await?;
+    Ok(())
+}
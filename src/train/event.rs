@@ -0,0 +1,121 @@
+//! Discord scheduled events that mirror planned trains, so that they show up
+//! in the guild's event list. Events are best effort: failures, e.g. from a
+//! missing Manage Events permission, are printed to stderr and otherwise
+//! ignored.
+
+use chrono::Duration;
+use poise::serenity_prelude::{
+    GuildId, Http, ScheduledEventId, ScheduledEventStatus, ScheduledEventType,
+};
+use sea_orm::{ActiveModelTrait, ColumnTrait, DbConn, EntityTrait, QueryFilter, Set};
+
+use entity::locale::Text;
+use entity::template::{TemplateKey, Templates, Vars};
+use entity::theme::Theme;
+use entity::{scheduled_train, train};
+
+/// How long events are shown to last; external events need an end time.
+fn length() -> Duration {
+    Duration::hours(1)
+}
+
+/// Creates the event for `scheduled`, returning its ID.
+#[allow(clippy::too_many_arguments)]
+pub async fn create(
+    http: &Http,
+    guild_id: GuildId,
+    train: &train::Model,
+    scheduled: &scheduled_train::Model,
+    conductor: &str,
+    location: Option<&str>,
+    theme: &Theme,
+    templates: &Templates,
+) -> Option<i64> {
+    let locale = templates.locale();
+    let vars = Vars::schedule(train, scheduled, theme, locale);
+    // Event names are limited to 100 characters.
+    let name: String = templates
+        .render(TemplateKey::MonitorTitle, &vars)
+        .chars()
+        .take(100)
+        .collect();
+    let mut description = format!("{}: {}", locale.text(Text::Conductor), conductor);
+    if let Some(notes) = &scheduled.notes {
+        description.push('\n');
+        description.push_str(notes);
+    }
+    let location = match location {
+        Some(location) => format!("{}: {}", train.world, location),
+        None => train.world.to_string(),
+    };
+    let event = guild_id
+        .create_scheduled_event(http, |e| {
+            e.name(name)
+                .description(description)
+                .kind(ScheduledEventType::External)
+                .location(location)
+                .start_time(scheduled.start_time)
+                .end_time(scheduled.start_time + length())
+        })
+        .await;
+    match event {
+        Ok(event) => Some(event.id.0 as i64),
+        Err(e) => {
+            eprintln!("Warning: Unable to create scheduled event: {}", e);
+            None
+        }
+    }
+}
+
+async fn set_status(http: &Http, guild_id: GuildId, event_id: i64, status: ScheduledEventStatus) {
+    let result = guild_id
+        .edit_scheduled_event(http, ScheduledEventId(event_id as u64), |e| {
+            e.status(status)
+        })
+        .await;
+    if let Err(e) = result {
+        eprintln!(
+            "Warning: Unable to update scheduled event {}: {}",
+            event_id, e
+        );
+    }
+}
+
+/// Marks the event of a planned train that has just been started as active.
+pub async fn start(http: &Http, guild_id: GuildId, scheduled: &scheduled_train::Model) {
+    if let Some(event_id) = scheduled.event_id {
+        set_status(http, guild_id, event_id, ScheduledEventStatus::Active).await;
+    }
+}
+
+/// Cancels the event of a planned train that is being dropped.
+pub async fn cancel(http: &Http, guild_id: GuildId, scheduled: &scheduled_train::Model) {
+    if let Some(event_id) = scheduled.event_id {
+        set_status(http, guild_id, event_id, ScheduledEventStatus::Canceled).await;
+    }
+}
+
+/// Completes the events of the started planned trains of `train_id`, once
+/// the train is done.
+pub async fn complete(
+    http: &Http,
+    db: &DbConn,
+    guild_id: GuildId,
+    train_id: i64,
+) -> eyre::Result<()> {
+    let running = scheduled_train::Entity::find()
+        .filter(scheduled_train::Column::TrainId.eq(train_id))
+        .filter(scheduled_train::Column::StartedAt.is_not_null())
+        .filter(scheduled_train::Column::EventId.is_not_null())
+        .all(db)
+        .await?;
+    for scheduled in running {
+        if let Some(event_id) = scheduled.event_id {
+            set_status(http, guild_id, event_id, ScheduledEventStatus::Completed).await;
+        }
+        let mut scheduled = scheduled_train::ActiveModel::from(scheduled);
+        scheduled.event_id = Set(None);
+        scheduled.update(db).await?;
+    }
+    Ok(())
+}
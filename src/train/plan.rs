@@ -0,0 +1,116 @@
+//! Planning trains ahead of time, including the occurrences of recurring
+//! trains.
+
+use chrono::{DateTime, Duration, Utc};
+use poise::serenity_prelude::{GuildId, Http, UserId};
+use sea_orm::{ActiveModelTrait, ColumnTrait, DbConn, EntityTrait, NotSet, QueryFilter, Set};
+
+use super::event;
+use entity::locale::Locale;
+use entity::template::Templates;
+use entity::theme::Theme;
+use entity::{recurring_train, scheduled_train, train};
+
+/// How far ahead the occurrences of recurring trains are planned.
+fn horizon() -> Duration {
+    Duration::days(14)
+}
+
+/// Saves a planned train and creates its Discord event.
+pub async fn create(
+    http: &Http,
+    db: &DbConn,
+    guild_id: GuildId,
+    train: &train::Model,
+    scheduled: scheduled_train::ActiveModel,
+    conductor: &str,
+    location: Option<&str>,
+) -> eyre::Result<scheduled_train::Model> {
+    let scheduled = scheduled.insert(db).await?;
+    let theme = Theme::load(db, guild_id.0 as i64).await?;
+    let locale = Locale::for_guild(db, guild_id.0 as i64).await?;
+    let templates = Templates::load(db, guild_id.0 as i64, locale).await?;
+    let event_id = event::create(
+        http, guild_id, train, &scheduled, conductor, location, &theme, &templates,
+    )
+    .await;
+    if event_id.is_none() {
+        return Ok(scheduled);
+    }
+    let mut scheduled = scheduled_train::ActiveModel::from(scheduled);
+    scheduled.event_id = Set(event_id);
+    Ok(scheduled.update(db).await?)
+}
+
+/// Plans the occurrences of `series` that start after `now` and within the
+/// horizon, and have not been planned yet. Returns the newly planned ones.
+pub async fn generate(
+    http: &Http,
+    db: &DbConn,
+    guild_id: GuildId,
+    series: recurring_train::Model,
+    now: DateTime<Utc>,
+) -> eyre::Result<Vec<scheduled_train::Model>> {
+    if series.paused {
+        return Ok(Vec::new());
+    }
+    let after = series.planned_until.map_or(now, |until| until.max(now));
+    let mut start_time = series.next_after(after);
+    if start_time > now + horizon() {
+        return Ok(Vec::new());
+    }
+    let Some(train) = train::Entity::find_by_id(series.train_id).one(db).await? else {
+        return Ok(Vec::new());
+    };
+    let conductor = match UserId(series.conductor as u64).to_user(http).await {
+        Ok(user) => user.name,
+        Err(_) => format!("<@{}>", series.conductor),
+    };
+
+    let mut planned = Vec::new();
+    while start_time <= now + horizon() {
+        let scheduled = scheduled_train::ActiveModel {
+            id: NotSet,
+            train_id: Set(series.train_id),
+            start_time: Set(start_time),
+            conductor: Set(series.conductor),
+            notes: Set(series.notes.clone()),
+            remind_minutes: Set(series.remind_minutes),
+            channel_id: Set(series.channel_id),
+            reminded: Set(false),
+            started_at: Set(None),
+            event_id: Set(None),
+            series_id: Set(Some(series.id)),
+        };
+        let location = series.location.as_deref();
+        planned.push(create(http, db, guild_id, &train, scheduled, &conductor, location).await?);
+        start_time = series.next_after(start_time);
+    }
+
+    let mut series = recurring_train::ActiveModel::from(series);
+    series.planned_until = Set(planned.last().map(|s| s.start_time));
+    series.update(db).await?;
+    Ok(planned)
+}
+
+/// Drops the occurrences of a series that have not started yet, cancelling
+/// their events. Returns the dropped ones.
+pub async fn drop_pending(
+    http: &Http,
+    db: &DbConn,
+    guild_id: GuildId,
+    series_id: i64,
+) -> eyre::Result<Vec<scheduled_train::Model>> {
+    let pending = scheduled_train::Entity::find()
+        .filter(scheduled_train::Column::SeriesId.eq(series_id))
+        .filter(scheduled_train::Column::StartedAt.is_null())
+        .all(db)
+        .await?;
+    for scheduled in &pending {
+        event::cancel(http, guild_id, scheduled).await;
+        scheduled_train::Entity::delete_by_id(scheduled.id)
+            .exec(db)
+            .await?;
+    }
+    Ok(pending)
+}